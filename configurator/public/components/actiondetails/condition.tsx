@@ -3,6 +3,8 @@ import { h, Fragment } from "preact";
 import { Condition } from "/types";
 import OBSConditionDetails from "./obscondition";
 import VTSConditionDetails from "./vtscondition";
+import MQTTConditionDetails from "./mqttcondition";
+import GeneralConditionDetails from "./generalcondition";
 
 interface ConditionDetailsProps {
   cond: Condition;
@@ -15,6 +17,10 @@ const ConditionDetails = (props: ConditionDetailsProps) => {
       return <OBSConditionDetails cond={props.cond} />;
     case "VTS":
       return <VTSConditionDetails cond={props.cond} msgFunc={props.msgFunc} />;
+    case "MQTT":
+      return <MQTTConditionDetails cond={props.cond} />;
+    case "General":
+      return <GeneralConditionDetails cond={props.cond} />;
     default:
       return <Fragment />;
   }
@@ -45,6 +45,106 @@ const GeneralActionDetails = ({ content: prop }: GeneralActionDetailsProps) => {
           )}
         </Fragment>
       );
+    case "RunCommandWithOptions":
+      return (
+        <Fragment>
+          {content.detached ? "Start" : "Run"} the command "{content.cmd}" with{" "}
+          {content.args.length === 0 ? "no" : content.args.length} argument
+          {content.args.length !== 1 && "s"}
+          {content.timeout_sec !== null &&
+            `, killed after ${content.timeout_sec} seconds`}
+          {content.stdout_var !== null &&
+            `, storing the output in "${content.stdout_var}"`}
+          <button
+            hidden={content.args.length === 0}
+            type="button"
+            onClick={toggleExpand}
+          >
+            {expand ? "Hide" : "Show"} arguments
+          </button>
+          {expand ? (
+            <ol>
+              {content.args.map((arg) => {
+                return <li key={arg}>{arg}</li>;
+              })}
+            </ol>
+          ) : (
+            ""
+          )}
+        </Fragment>
+      );
+    case "HttpRequest":
+      return (
+        <Fragment>
+          Send a {content.method.toUpperCase()} request to "{content.url}"
+          {content.response_var !== null &&
+            `, storing the response${
+              content.json_pointer === null ? "" : ` at ${content.json_pointer}`
+            } in "${content.response_var}"`}
+        </Fragment>
+      );
+    case "WriteFile":
+      return (
+        <Fragment>
+          Write "{content.text}" to the file "{content.path}"
+        </Fragment>
+      );
+    case "AppendLineToFile":
+      return (
+        <Fragment>
+          Add the line "{content.text}" to the file "{content.path}"
+        </Fragment>
+      );
+    case "ReplaceLineInFile":
+      return (
+        <Fragment>
+          Replace line {content.line} of the file "{content.path}" with "
+          {content.text}"
+        </Fragment>
+      );
+    case "IncrementFileCounter":
+      return (
+        <Fragment>
+          Add {content.amount} to the counter in the file "{content.path}"
+          {content.counter_var !== null &&
+            `, storing the result in "${content.counter_var}"`}
+        </Fragment>
+      );
+    case "StartCountdown":
+      return (
+        <Fragment>
+          Start the countdown "{content.name}" from {content.duration_sec}{" "}
+          seconds
+          {content.output_file !== null &&
+            `, writing it to the file "${content.output_file}"`}
+        </Fragment>
+      );
+    case "StartStopwatch":
+      return (
+        <Fragment>
+          Start the stopwatch "{content.name}"
+          {content.output_file !== null &&
+            `, writing it to the file "${content.output_file}"`}
+        </Fragment>
+      );
+    case "StopTimer":
+      return <Fragment>Stop the timer "{content}"</Fragment>;
+    case "ResumeTimer":
+      return <Fragment>Resume the timer "{content}"</Fragment>;
+    case "ResetTimer":
+      return <Fragment>Reset the timer "{content}"</Fragment>;
+    case "EnableSchedule":
+      return <Fragment>Enable the schedule "{content}"</Fragment>;
+    case "DisableSchedule":
+      return <Fragment>Disable the schedule "{content}"</Fragment>;
+    case "ToggleSchedule":
+      return <Fragment>Toggle the schedule "{content}"</Fragment>;
+    case "SetVariable":
+      return (
+        <Fragment>
+          Set the variable "{content[0]}" to "{content[1]}"
+        </Fragment>
+      );
     default:
       return <Fragment />;
   }
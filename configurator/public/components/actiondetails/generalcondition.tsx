@@ -0,0 +1,75 @@
+import { h, Fragment } from "preact";
+
+import { Condition, GeneralQuery } from "/types";
+
+interface GeneralConditionDetailsProps {
+  cond: Condition;
+}
+
+const GeneralConditionDetails = (props: GeneralConditionDetailsProps) => {
+  const query = props.cond.query.content as GeneralQuery;
+
+  if ("Variable" in query) {
+    return (
+      <Fragment>
+        If the variable "{query.Variable}" is: {props.cond.target}
+      </Fragment>
+    );
+  }
+
+  if ("TimerRunning" in query) {
+    return (
+      <Fragment>
+        If the timer "{query.TimerRunning}" is running: {props.cond.target}
+      </Fragment>
+    );
+  }
+
+  if ("TimerFinished" in query) {
+    return (
+      <Fragment>
+        If the countdown "{query.TimerFinished}" has finished:{" "}
+        {props.cond.target}
+      </Fragment>
+    );
+  }
+
+  if ("TimerSeconds" in query) {
+    return (
+      <Fragment>
+        If the seconds on the timer "{query.TimerSeconds}" are:{" "}
+        {props.cond.target}
+      </Fragment>
+    );
+  }
+
+  if ("TimerText" in query) {
+    return (
+      <Fragment>
+        If the timer "{query.TimerText}" shows: {props.cond.target}
+      </Fragment>
+    );
+  }
+
+  if ("ScheduleEnabled" in query) {
+    return (
+      <Fragment>
+        If the schedule "{query.ScheduleEnabled}" is enabled:{" "}
+        {props.cond.target}
+      </Fragment>
+    );
+  }
+
+  const request = query.HttpRequest;
+
+  return (
+    <Fragment>
+      If the response of a {request.method.toUpperCase()} request to "
+      {request.url}"
+      {request.json_pointer !== null && ` at ${request.json_pointer}`} is:{" "}
+      {props.cond.target}
+    </Fragment>
+  );
+};
+
+export default GeneralConditionDetails;
@@ -0,0 +1,20 @@
+import { h, Fragment } from "preact";
+
+import { MQTTAction } from "/types";
+
+interface MQTTActionDetailsProps {
+  content: MQTTAction;
+}
+
+const MQTTActionDetails = ({ content: prop }: MQTTActionDetailsProps) => {
+  const { content } = prop;
+
+  return (
+    <Fragment>
+      Publish "{content.payload}" to the MQTT topic "{content.topic}"
+      {content.retain && " as the retained message"}
+    </Fragment>
+  );
+};
+
+export default MQTTActionDetails;
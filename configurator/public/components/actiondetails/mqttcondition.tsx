@@ -0,0 +1,20 @@
+import { h, Fragment } from "preact";
+
+import { Condition, MQTTQuery } from "/types";
+
+interface MQTTConditionDetailsProps {
+  cond: Condition;
+}
+
+const MQTTConditionDetails = (props: MQTTConditionDetailsProps) => {
+  const query = props.cond.query.content as MQTTQuery;
+
+  return (
+    <Fragment>
+      If the last value of the MQTT topic "{query.LastValue}" is:{" "}
+      {props.cond.target}
+    </Fragment>
+  );
+};
+
+export default MQTTConditionDetails;
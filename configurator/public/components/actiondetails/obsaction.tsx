@@ -1,6 +1,14 @@
 import { h, Fragment } from "preact";
 
-import { OBSAction } from "/types";
+import {
+  OBSAction,
+  OBSSetInputTextData,
+  OBSSetInputSettingsData,
+  OBSMediaSeekData,
+  OBSWaitForMediaEndData,
+  OBSSaveScreenshotData,
+  OBSVendorRequestData,
+} from "/types";
 
 interface OBSActionDetailsProps {
   content: OBSAction;
@@ -11,9 +19,38 @@ const OBSActionDetails = (props: OBSActionDetailsProps) => {
     case "ProgramSceneChange":
       return (
         <Fragment>
-          Change OBS Program Scene to: {props.content.content}
+          Change OBS Program Scene to: {props.content.content as string}
         </Fragment>
       );
+    case "SceneCollectionChange":
+      return (
+        <Fragment>
+          Change OBS Scene Collection to: {props.content.content as string}
+        </Fragment>
+      );
+    case "ProfileChange":
+      return (
+        <Fragment>
+          Change OBS Profile to: {props.content.content as string}
+        </Fragment>
+      );
+    case "SetInputText": {
+      const data = props.content.content as OBSSetInputTextData;
+      return (
+        <Fragment>
+          Set the text of OBS input "{data.input}" to: {data.text}
+        </Fragment>
+      );
+    }
+    case "SetInputSettings": {
+      const data = props.content.content as OBSSetInputSettingsData;
+      return (
+        <Fragment>
+          {data.overlay ? "Update" : "Replace"} the settings of OBS input "
+          {data.input}" with: {JSON.stringify(data.settings)}
+        </Fragment>
+      );
+    }
     case "StartStream":
       return <Fragment>Start OBS Stream</Fragment>;
     case "StopStream":
@@ -22,6 +59,75 @@ const OBSActionDetails = (props: OBSActionDetailsProps) => {
       return <Fragment>Start OBS Recording</Fragment>;
     case "StopRecord":
       return <Fragment>Stop OBS Recording</Fragment>;
+    case "MediaPlay":
+      return (
+        <Fragment>
+          Play OBS media input: {props.content.content as string}
+        </Fragment>
+      );
+    case "MediaPause":
+      return (
+        <Fragment>
+          Pause OBS media input: {props.content.content as string}
+        </Fragment>
+      );
+    case "MediaStop":
+      return (
+        <Fragment>
+          Stop OBS media input: {props.content.content as string}
+        </Fragment>
+      );
+    case "MediaRestart":
+      return (
+        <Fragment>
+          Restart OBS media input: {props.content.content as string}
+        </Fragment>
+      );
+    case "MediaSeek": {
+      const data = props.content.content as OBSMediaSeekData;
+      return (
+        <Fragment>
+          Seek OBS media input "{data.input}" to {data.time_sec} seconds
+        </Fragment>
+      );
+    }
+    case "WaitForMediaEnd": {
+      const data = props.content.content as OBSWaitForMediaEndData;
+      return (
+        <Fragment>
+          Wait for OBS media input "{data.input}" to finish
+          {data.timeout_sec !== null &&
+            ` (for up to ${data.timeout_sec} seconds)`}
+        </Fragment>
+      );
+    }
+    case "SaveScreenshot": {
+      const data = props.content.content as OBSSaveScreenshotData;
+      return (
+        <Fragment>
+          Save a screenshot of{" "}
+          {data.source === null
+            ? "the OBS program output"
+            : `OBS source "${data.source}"`}{" "}
+          to: {data.path}
+        </Fragment>
+      );
+    }
+    case "TriggerHotkey":
+      return (
+        <Fragment>
+          Trigger OBS hotkey: {props.content.content as string}
+        </Fragment>
+      );
+    case "VendorRequest": {
+      const data = props.content.content as OBSVendorRequestData;
+      return (
+        <Fragment>
+          Send the "{data.request_type}" request to OBS vendor "{data.vendor}"
+          with: {JSON.stringify(data.data)}
+        </Fragment>
+      );
+    }
     default:
       return <Fragment />;
   }
@@ -7,13 +7,52 @@ interface OBSConditionDetailsProps {
 }
 
 const OBSConditionDetails = (props: OBSConditionDetailsProps) => {
-  switch (props.cond.query.content as OBSQuery) {
+  const query = props.cond.query.content as OBSQuery;
+
+  if (typeof query === "object") {
+    if ("MediaState" in query) {
+      return (
+        <Fragment>
+          If the OBS media input "{query.MediaState}" state is{" "}
+          {props.cond.target}
+        </Fragment>
+      );
+    }
+
+    if ("MediaCursor" in query) {
+      return (
+        <Fragment>
+          If the OBS media input "{query.MediaCursor}" position is{" "}
+          {props.cond.target} seconds
+        </Fragment>
+      );
+    }
+
+    return (
+      <Fragment>
+        If the OBS media input "{query.MediaDuration}" duration is{" "}
+        {props.cond.target} seconds
+      </Fragment>
+    );
+  }
+
+  switch (query) {
     case "CurrentProgramScene":
       return (
         <Fragment>
           If the OBS current program scene is {props.cond.target}
         </Fragment>
       );
+    case "CurrentSceneCollection":
+      return (
+        <Fragment>
+          If the OBS current scene collection is {props.cond.target}
+        </Fragment>
+      );
+    case "CurrentProfile":
+      return (
+        <Fragment>If the OBS current profile is {props.cond.target}</Fragment>
+      );
     case "IsStreaming":
       return (
         <Fragment>
@@ -0,0 +1,24 @@
+import { h, Fragment } from "preact";
+
+import { OSCAction } from "/types";
+
+interface OSCActionDetailsProps {
+  content: OSCAction;
+}
+
+const OSCActionDetails = ({ content: prop }: OSCActionDetailsProps) => {
+  const { content } = prop;
+
+  const args = content.args.map((arg) =>
+    arg.tag === "String" ? `"${arg.content}"` : arg.content.toString(),
+  );
+
+  return (
+    <Fragment>
+      Send the OSC message "{content.address}" to {content.target}
+      {args.length > 0 && ` with ${args.join(", ")}`}
+    </Fragment>
+  );
+};
+
+export default OSCActionDetails;
@@ -1,8 +1,19 @@
 import { h, Fragment } from "preact";
 
-import { SingleAction, OBSAction, VTSAction, GeneralAction } from "/types";
+import {
+  SingleAction,
+  OBSAction,
+  VTSAction,
+  OSCAction,
+  MQTTAction,
+  SocketAction,
+  GeneralAction,
+} from "/types";
 import OBSActionDetails from "./obsaction";
 import VTSActionDetails from "./vtsaction";
+import OSCActionDetails from "./oscaction";
+import MQTTActionDetails from "./mqttaction";
+import SocketActionDetails from "./socketaction";
 import GeneralActionDetails from "./generalaction";
 
 interface SingleActionDetailsProps {
@@ -22,6 +33,16 @@ const SingleActionDetails = (props: SingleActionDetailsProps) => {
           msgFunc={props.msgFunc}
         />
       );
+    case "OSC":
+      return <OSCActionDetails content={props.content.content as OSCAction} />;
+    case "MQTT":
+      return (
+        <MQTTActionDetails content={props.content.content as MQTTAction} />
+      );
+    case "Socket":
+      return (
+        <SocketActionDetails content={props.content.content as SocketAction} />
+      );
     case "General":
       return (
         <GeneralActionDetails
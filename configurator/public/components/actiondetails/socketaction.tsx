@@ -0,0 +1,28 @@
+import { h, Fragment } from "preact";
+
+import { SocketAction } from "/types";
+
+interface SocketActionDetailsProps {
+  content: SocketAction;
+}
+
+const SocketActionDetails = ({ content: prop }: SocketActionDetailsProps) => {
+  switch (prop.tag) {
+    case "SendText":
+      return (
+        <Fragment>
+          Send "{prop.content.text}" to {prop.content.endpoint}
+        </Fragment>
+      );
+    case "SendJson":
+      return (
+        <Fragment>
+          Send {JSON.stringify(prop.content.json)} to {prop.content.endpoint}
+        </Fragment>
+      );
+    default:
+      return <Fragment />;
+  }
+};
+
+export default SocketActionDetails;
@@ -20,8 +20,25 @@ const VTSActionDetails = ({ content, msgFunc }: VTSActionDetailsProps) => {
   tagToStringMap.set("EnableExpression", "Enable VTube Studio expression:");
   tagToStringMap.set("DisableExpression", "Disable VTube Studio expression:");
   tagToStringMap.set("LoadModel", "Load VTube Studio model:");
-  tagToStringMap.set("MoveModel", "Move VTube Studio model to:");
+  tagToStringMap.set("MoveModel", "Move VTube Studio model");
   tagToStringMap.set("TriggerHotkey", "Trigger VTube Studio hotkey:");
+  tagToStringMap.set(
+    "ToggleExpressionByName",
+    "Toggle VTube Studio expression named:",
+  );
+  tagToStringMap.set(
+    "EnableExpressionByName",
+    "Enable VTube Studio expression named:",
+  );
+  tagToStringMap.set(
+    "DisableExpressionByName",
+    "Disable VTube Studio expression named:",
+  );
+  tagToStringMap.set("LoadModelByName", "Load VTube Studio model named:");
+  tagToStringMap.set(
+    "TriggerHotkeyByName",
+    "Trigger VTube Studio hotkey named:",
+  );
   tagToStringMap.set(
     "SaveCurrentModelPosition",
     "Save VTube Studio model position as:",
@@ -30,6 +47,32 @@ const VTSActionDetails = ({ content, msgFunc }: VTSActionDetailsProps) => {
     "RestoreModelPosition",
     "Restore VTube Studio model position from:",
   );
+  tagToStringMap.set(
+    "DeleteModelPosition",
+    "Delete VTube Studio model position:",
+  );
+  tagToStringMap.set("InjectParameters", "Set VTube Studio parameters:");
+  tagToStringMap.set(
+    "StopInjectingParameters",
+    "Stop setting VTube Studio parameters:",
+  );
+  tagToStringMap.set("CreateParameter", "Create VTube Studio parameter:");
+  tagToStringMap.set("ColorTint", "Tint VTube Studio art meshes:");
+  tagToStringMap.set("OverridePhysics", "Override VTube Studio physics:");
+  tagToStringMap.set(
+    "StopOverridingPhysics",
+    "Stop overriding VTube Studio physics",
+  );
+  tagToStringMap.set("SetNdiActive", "Set VTube Studio NDI output active:");
+  tagToStringMap.set("ToggleNdi", "Toggle VTube Studio NDI output");
+  tagToStringMap.set("LoadItem", "Load VTube Studio item:");
+  tagToStringMap.set("UnloadItems", "Unload VTube Studio items:");
+  tagToStringMap.set("PinItem", "Pin VTube Studio item:");
+  tagToStringMap.set("UnpinItem", "Unpin VTube Studio item:");
+  tagToStringMap.set(
+    "ControlItemAnimation",
+    "Control VTube Studio item animation:",
+  );
 
   const convertIdToStr = useCallback(
     (id: string, invokeCmd: string) => {
@@ -73,8 +116,90 @@ const VTSActionDetails = ({ content, msgFunc }: VTSActionDetailsProps) => {
       return data;
     }
 
+    if (typeof data === "boolean") {
+      return data ? "yes" : "no";
+    }
+
+    if (Array.isArray(data)) {
+      return data.join(", ");
+    }
+
+    if ("parameters" in data) {
+      const params = data.parameters
+        .map((p) => `${p.id} ${data.add ? "+" : "="} ${p.value}`)
+        .join(", ");
+      return data.duration_sec === null
+        ? params
+        : `${params} for ${data.duration_sec} seconds`;
+    }
+
+    if ("default_value" in data) {
+      return `"${data.name}" (${data.min} to ${data.max}, default ${data.default_value})`;
+    }
+
+    if ("multipliers" in data) {
+      const changes = [
+        data.strength === null ? null : `strength ${data.strength}`,
+        data.wind === null ? null : `wind ${data.wind}`,
+        ...data.multipliers.map(
+          (m) =>
+            `${m.group_id} x${m.strength ?? 1} strength, x${m.wind ?? 1} wind`,
+        ),
+      ].filter((c) => c !== null);
+      return `${changes.join(", ")} for ${data.duration_sec} seconds`;
+    }
+
+    if ("tag_contains" in data) {
+      const targets = data.all
+        ? "all"
+        : [
+            ...data.names,
+            ...data.name_contains.map((n) => `*${n}*`),
+            ...data.tags.map((t) => `tag ${t}`),
+            ...data.tag_contains.map((t) => `tag *${t}*`),
+          ].join(", ");
+      const color = data.rainbow
+        ? "rainbow"
+        : `rgba(${data.r}, ${data.g}, ${data.b}, ${data.a})`;
+      return data.reset_after_sec === null
+        ? `${targets} with ${color}`
+        : `${targets} with ${color} for ${data.reset_after_sec} seconds`;
+    }
+
+    if ("file_name" in data) {
+      const pos = `"${data.file_name}" at (${data.x}, ${data.y}), with rotation ${data.rotation}, size ${data.size}`;
+      return data.instance_var === null
+        ? pos
+        : `${pos}, storing ID in "${data.instance_var}"`;
+    }
+
+    if ("file_names" in data) {
+      return [...data.instance_ids, ...data.file_names].join(", ");
+    }
+
+    if ("art_mesh_id" in data) {
+      return `"${data.instance_id}" to "${data.art_mesh_id}"`;
+    }
+
+    if ("framerate" in data) {
+      const changes = [
+        data.play === null ? null : data.play ? "play" : "stop",
+        data.frame === null ? null : `frame ${data.frame}`,
+        data.framerate === null ? null : `${data.framerate} fps`,
+        data.brightness === null ? null : `brightness ${data.brightness}`,
+        data.opacity === null ? null : `opacity ${data.opacity}`,
+      ].filter((c) => c !== null);
+      return `"${data.instance_id}" ${changes.join(", ")}`;
+    }
+
     if ("x" in data) {
-      return `(${data.x}, ${data.y}), with rotation ${data.rotation}, size ${data.size}, with duration ${data.time_sec}`;
+      const changes = [
+        data.x === null ? null : `x ${data.x}`,
+        data.y === null ? null : `y ${data.y}`,
+        data.rotation === null ? null : `rotation ${data.rotation}`,
+        data.size === null ? null : `size ${data.size}`,
+      ].filter((c) => c !== null);
+      return `${data.relative ? "by" : "to"} ${changes.join(", ")}, with duration ${data.time_sec}`;
     }
 
     return `"${data.var_id}" with duration ${data.time_sec}`;
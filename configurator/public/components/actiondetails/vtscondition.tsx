@@ -11,15 +11,67 @@ interface VTSConditionDetailsProps {
 
 const VTSConditionDetails = ({ cond, msgFunc }: VTSConditionDetailsProps) => {
   const [target, setTarget] = useState<string>(cond.target);
+  const [exprName, setExprName] = useState<string>("");
 
   const tagToStringMap = new Map<string, string>();
 
   tagToStringMap.set("ActiveModelId", "the current VTube Studio model is");
   tagToStringMap.set(
     "StoredModelPositionExists",
-    'the statement "There exists a stored VTube Studio model position" is',
+    'the statement "There exists a stored position for the current VTube Studio model" is',
   );
 
+  tagToStringMap.set("Version", "the VTube Studio version is");
+  tagToStringMap.set(
+    "PhysicsStrength",
+    "the VTube Studio model physics strength is",
+  );
+  tagToStringMap.set("PhysicsWind", "the VTube Studio model wind strength is");
+  tagToStringMap.set(
+    "PhysicsEnabled",
+    'the statement "VTube Studio model physics are enabled" is',
+  );
+  tagToStringMap.set(
+    "PhysicsOverrideActive",
+    'the statement "VTube Studio physics are being overridden" is',
+  );
+  tagToStringMap.set(
+    "NdiActive",
+    'the statement "VTube Studio NDI output is active" is',
+  );
+  tagToStringMap.set(
+    "SceneLightingActive",
+    'the statement "VTube Studio scene lighting is active" is',
+  );
+  tagToStringMap.set(
+    "SceneLightingColor",
+    "the VTube Studio scene lighting color is",
+  );
+
+  tagToStringMap.set(
+    "FaceFound",
+    'the statement "VTube Studio face tracking is found" is',
+  );
+  tagToStringMap.set("ActiveModelName", "the current VTube Studio model is");
+  tagToStringMap.set("ItemCount", "the number of VTube Studio items is");
+  tagToStringMap.set("ModelPositionX", "the VTube Studio model X position is");
+  tagToStringMap.set("ModelPositionY", "the VTube Studio model Y position is");
+  tagToStringMap.set("ModelRotation", "the VTube Studio model rotation is");
+  tagToStringMap.set("ModelSize", "the VTube Studio model size is");
+  useEffect(() => {
+    const query = cond.query.content as VTSQuery;
+    if (typeof query === "object" && "ExpressionActive" in query) {
+      invoke("get_vts_expression_name_from_id", { id: query.ExpressionActive })
+        .then((nameRaw) => {
+          setExprName(nameRaw as string);
+        })
+        .catch(() => {
+          // The expression may belong to a model that isn't loaded right now
+          setExprName(query.ExpressionActive);
+        });
+    }
+  }, [cond]);
+
   useEffect(() => {
     switch (cond.query.content as VTSQuery) {
       case "ActiveModelId":
@@ -38,9 +90,28 @@ const VTSConditionDetails = ({ cond, msgFunc }: VTSConditionDetailsProps) => {
     }
   }, [cond, msgFunc]);
 
+  const query = cond.query.content as VTSQuery;
+
+  if (typeof query === "object" && "ExpressionActive" in query) {
+    return (
+      <Fragment>
+        If the VTube Studio expression "{exprName}" being active is: {target}
+      </Fragment>
+    );
+  }
+
+  if (typeof query === "object") {
+    return (
+      <Fragment>
+        If the VTube Studio model position "{query.ModelPositionExists}" is
+        stored: {target}
+      </Fragment>
+    );
+  }
+
   return (
     <Fragment>
-      If {tagToStringMap.get(cond.query.content)}: {target}
+      If {tagToStringMap.get(query)}: {target}
     </Fragment>
   );
 };
@@ -7,6 +7,8 @@ import SelectOptsGen from "/components/selectoptsgen";
 enum OBSActionType {
   None,
   ProgramSceneChange,
+  SceneCollectionChange,
+  ProfileChange,
   StartStream,
   StopStream,
   StartRecord,
@@ -56,22 +58,40 @@ class EditOBSAction extends Component<EditOBSActionProps, EditOBSActionState> {
     this.actionTypeUpdate(this.state.actionType, true);
   }
 
+  loadActionInputList = (
+    cmd: string,
+    newActionType: OBSActionType,
+    init: boolean,
+  ) => {
+    invoke(cmd)
+      .then((list) => {
+        this.setState({
+          actionType: newActionType,
+          actionInputList: list as string[],
+          showActionInput: true,
+          // if called on init leave actionInput alone, otherwise reset
+          actionInput: init ? this.state.actionInput : "none",
+        });
+      })
+      .catch((err) => {
+        this.props.msgFunc(`Error occurred: ${err.toString()}`);
+      });
+  };
+
   actionTypeUpdate = (newActionType: OBSActionType, init: boolean) => {
     switch (newActionType) {
       case OBSActionType.ProgramSceneChange:
-        invoke("get_obs_scenes")
-          .then((list) => {
-            this.setState({
-              actionType: newActionType,
-              actionInputList: list as string[],
-              showActionInput: true,
-              // if called on init leave actionInput alone, otherwise reset
-              actionInput: init ? this.state.actionInput : "none",
-            });
-          })
-          .catch((err) => {
-            this.props.msgFunc(`Error occurred: ${err.toString()}`);
-          });
+        this.loadActionInputList("get_obs_scenes", newActionType, init);
+        break;
+      case OBSActionType.SceneCollectionChange:
+        this.loadActionInputList(
+          "get_obs_scene_collections",
+          newActionType,
+          init,
+        );
+        break;
+      case OBSActionType.ProfileChange:
+        this.loadActionInputList("get_obs_profiles", newActionType, init);
         break;
       case OBSActionType.StartStream:
       case OBSActionType.StopStream:
@@ -118,6 +138,8 @@ class EditOBSAction extends Component<EditOBSActionProps, EditOBSActionState> {
         );
         return undefined;
       case OBSActionType.ProgramSceneChange:
+      case OBSActionType.SceneCollectionChange:
+      case OBSActionType.ProfileChange:
         if (this.state.actionInput === "none") {
           this.props.msgFunc(
             "Please select an option for the OBS Studio action parameter",
@@ -151,6 +173,10 @@ class EditOBSAction extends Component<EditOBSActionProps, EditOBSActionState> {
             <option value={OBSActionType.ProgramSceneChange}>
               Program Scene Change
             </option>
+            <option value={OBSActionType.SceneCollectionChange}>
+              Scene Collection Change
+            </option>
+            <option value={OBSActionType.ProfileChange}>Profile Change</option>
             <option value={OBSActionType.StartStream}>Start Streaming</option>
             <option value={OBSActionType.StopStream}>Stop Streaming</option>
             <option value={OBSActionType.StartRecord}>Start Recording</option>
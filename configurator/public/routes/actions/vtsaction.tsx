@@ -39,6 +39,7 @@ interface EditVTSActionState {
   textInputValue: string;
   showRestorePosInput: boolean;
   loadedRestorePosData?: VTSRestoreModelPositionData;
+  byName: boolean;
 }
 
 class EditVTSAction extends Component<EditVTSActionProps, EditVTSActionState> {
@@ -50,9 +51,15 @@ class EditVTSAction extends Component<EditVTSActionProps, EditVTSActionState> {
     let loadedModelPosData: VTSMoveModelData | undefined;
     let showRestorePosInput = false;
     let loadedRestorePosData: VTSRestoreModelPositionData | undefined;
+    let byName = false;
 
     if (props.data) {
-      actionType = VTSActionType[props.data.tag as keyof typeof VTSActionType];
+      // The ByName variants use the same inputs, just with the name kept as is
+      byName = props.data.tag.endsWith("ByName");
+      actionType =
+        VTSActionType[
+          props.data.tag.replace(/ByName$/, "") as keyof typeof VTSActionType
+        ];
 
       if (actionType === VTSActionType.MoveModel) {
         showModelPosInput = true;
@@ -77,6 +84,7 @@ class EditVTSAction extends Component<EditVTSActionProps, EditVTSActionState> {
       textInputValue: "",
       showRestorePosInput,
       loadedRestorePosData,
+      byName,
     };
   }
 
@@ -107,8 +115,13 @@ class EditVTSAction extends Component<EditVTSActionProps, EditVTSActionState> {
         });
 
         // Unless init is true, in which case we get the loaded ID
-        // and convert it to the name
-        if (init && this.props.data && this.props.data.content) {
+        // and convert it to the name (if it isn't a name already)
+        if (init && this.state.byName && this.props.data) {
+          clearTimeout(timer);
+          this.setState({
+            selectInputValue: `x-${this.props.data.content as string}`,
+          });
+        } else if (init && this.props.data && this.props.data.content) {
           invoke(invokeArgInner, {
             id: this.props.data.content,
           })
@@ -223,6 +236,13 @@ class EditVTSAction extends Component<EditVTSActionProps, EditVTSActionState> {
       return undefined;
     }
 
+    if (this.state.byName) {
+      return {
+        tag: `${VTSActionType[this.state.actionType]}ByName`,
+        content: this.state.selectInputValue.substring(2),
+      } as VTSAction;
+    }
+
     try {
       return {
         tag: VTSActionType[this.state.actionType],
@@ -327,6 +347,16 @@ class EditVTSAction extends Component<EditVTSActionProps, EditVTSActionState> {
     });
   };
 
+  onByNameChange = (e: Event) => {
+    if (!e.target) {
+      return;
+    }
+
+    this.setState({
+      byName: (e.target as HTMLInputElement).checked,
+    });
+  };
+
   onTextInputChange = (e: Event) => {
     if (!e.target) {
       return;
@@ -375,6 +405,14 @@ class EditVTSAction extends Component<EditVTSActionProps, EditVTSActionState> {
             <SelectOptsGen opts={state.selectInputOptions} />
           </select>
         </label>
+        <label hidden={!state.showSelectInput}>
+          Look up by name when run (keeps working if the model is re-imported):
+          <input
+            type="checkbox"
+            checked={state.byName}
+            onChange={this.onByNameChange}
+          />
+        </label>
         <label hidden={!state.showTextInput}>
           Action parameter:
           <input
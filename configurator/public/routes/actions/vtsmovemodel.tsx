@@ -16,6 +16,7 @@ interface EditVTSMoveModelDataState {
   rotation: string;
   size: string;
   time: string;
+  relative: boolean;
   xValid: boolean;
   yValid: boolean;
   rotationValid: boolean;
@@ -35,27 +36,31 @@ class EditVTSMoveModelData extends Component<
     let rotation = "";
     let size = "";
     let time = "";
+    let relative = false;
     let loaded = false;
 
     if (props.data) {
-      x = props.data.x.toString();
-      y = props.data.y.toString();
-      rotation = props.data.rotation.toString();
-      size = props.data.size.toString();
+      x = props.data.x?.toString() ?? "";
+      y = props.data.y?.toString() ?? "";
+      rotation = props.data.rotation?.toString() ?? "";
+      size = props.data.size?.toString() ?? "";
       time = props.data.time_sec.toString();
+      relative = props.data.relative ?? false;
       loaded = true;
     }
 
+    // Empty position fields are valid since they mean the value is left unchanged
     this.state = {
       x,
       y,
       rotation,
       size,
       time,
-      xValid: loaded,
-      yValid: loaded,
-      rotationValid: loaded,
-      sizeValid: loaded,
+      relative,
+      xValid: true,
+      yValid: true,
+      rotationValid: true,
+      sizeValid: true,
       timeValid: loaded,
     };
   }
@@ -68,12 +73,10 @@ class EditVTSMoveModelData extends Component<
     const value = (e.target as HTMLInputElement).value;
 
     // Since the input type is number the value attribute will be empty
-    // if the input is not a valid number
-    const valid = value.length > 0;
-
+    // if the input is not a valid number, which is treated as leaving it unchanged
     this.setState({
       x: value,
-      xValid: valid,
+      xValid: true,
     });
   };
 
@@ -85,12 +88,10 @@ class EditVTSMoveModelData extends Component<
     const value = (e.target as HTMLInputElement).value;
 
     // Since the input type is number the value attribute will be empty
-    // if the input is not a valid number
-    const valid = value.length > 0;
-
+    // if the input is not a valid number, which is treated as leaving it unchanged
     this.setState({
       y: value,
-      yValid: valid,
+      yValid: true,
     });
   };
 
@@ -103,7 +104,8 @@ class EditVTSMoveModelData extends Component<
     const parsedVal = parseFloat(value);
 
     const valid =
-      !Number.isNaN(parsedVal) && parsedVal >= -360 && parsedVal <= 360;
+      value.length === 0 ||
+      (!Number.isNaN(parsedVal) && parsedVal >= -360 && parsedVal <= 360);
 
     this.setState({
       rotation: value,
@@ -120,7 +122,8 @@ class EditVTSMoveModelData extends Component<
     const parsedVal = parseFloat(value);
 
     const valid =
-      !Number.isNaN(parsedVal) && parsedVal >= -100 && parsedVal <= 100;
+      value.length === 0 ||
+      (!Number.isNaN(parsedVal) && parsedVal >= -100 && parsedVal <= 100);
 
     this.setState({
       size: value,
@@ -144,6 +147,16 @@ class EditVTSMoveModelData extends Component<
     });
   };
 
+  onRelativeChange = (e: Event) => {
+    if (!e.target) {
+      return;
+    }
+
+    this.setState({
+      relative: (e.target as HTMLInputElement).checked,
+    });
+  };
+
   getCurrentModelPos = () => {
     const timer = setTimeout(() => {
       this.props.msgFunc(
@@ -155,10 +168,11 @@ class EditVTSMoveModelData extends Component<
         clearTimeout(timer);
         const pos = posRaw as VTSMoveModelData;
         this.setState({
-          x: pos.x.toString(),
-          y: pos.y.toString(),
-          rotation: pos.rotation.toString(),
-          size: pos.size.toString(),
+          x: pos.x?.toString() ?? "",
+          y: pos.y?.toString() ?? "",
+          rotation: pos.rotation?.toString() ?? "",
+          size: pos.size?.toString() ?? "",
+          relative: false,
           // assume loaded values are valid since they are definitely numbers from the Rust side
           xValid: true,
           yValid: true,
@@ -186,12 +200,16 @@ class EditVTSMoveModelData extends Component<
       return undefined;
     }
 
+    const optionalFloat = (value: string) =>
+      value.length === 0 ? null : parseFloat(value);
+
     return {
-      x: parseFloat(this.state.x),
-      y: parseFloat(this.state.y),
-      rotation: parseFloat(this.state.rotation),
-      size: parseFloat(this.state.size),
+      x: optionalFloat(this.state.x),
+      y: optionalFloat(this.state.y),
+      rotation: optionalFloat(this.state.rotation),
+      size: optionalFloat(this.state.size),
       time_sec: parseFloat(this.state.time),
+      relative: this.state.relative,
     } as VTSMoveModelData;
   };
 
@@ -205,6 +223,18 @@ class EditVTSMoveModelData extends Component<
         >
           Get current model position
         </button>
+        <label class={style.rowDisp}>
+          <span class={style.cellDisp}>
+            Relative to the current position (empty fields are left
+            unchanged):
+          </span>
+          <input
+            class={style.cellDisp}
+            type="checkbox"
+            checked={state.relative}
+            onChange={this.onRelativeChange}
+          />
+        </label>
         <label class={style.rowDisp}>
           <span class={style.cellDisp}>X:</span>
           <input
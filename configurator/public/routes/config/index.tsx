@@ -27,6 +27,8 @@ const Config = () => {
   const [serverConfig, setServerConfig] = useState<ServerConfig | undefined>(
     undefined,
   );
+  // Plugins without a form here (OSC etc) are kept as they are when saving
+  const [otherPlugins, setOtherPlugins] = useState<PluginConfig[]>([]);
   const [statusState, setStatusState] = useState<string>("");
   const [configFolder, setConfigFolder] = useState<string>("");
   const [savedRecently, setSavedRecently] = useState<boolean>(false);
@@ -48,10 +50,12 @@ const Config = () => {
       setServerConfig({
         addr: loadedAppConfig.addr,
         port: loadedAppConfig.port,
+        file_output_dir: loadedAppConfig.file_output_dir ?? null,
       });
 
       let obsLoaded = false;
       let vtsLoaded = false;
+      const others: PluginConfig[] = [];
 
       for (const plugin of loadedAppConfig.plugins) {
         if (typeof plugin === "string") {
@@ -64,9 +68,13 @@ const Config = () => {
         } else if ("VTS" in plugin) {
           setVtsConfig(plugin.VTS);
           vtsLoaded = true;
+        } else {
+          others.push(plugin);
         }
       }
 
+      setOtherPlugins(others);
+
       if (!obsLoaded) {
         setObsConfig(getObsDefaults());
       }
@@ -105,12 +113,14 @@ const Config = () => {
     const plugins: PluginConfig[] = [
       { OBS: obsConfig },
       { VTS: vtsConfig },
+      ...otherPlugins,
       "General",
     ];
     const appConfig: AppConfig = {
       addr: serverConfig.addr,
       port: serverConfig.port,
       plugins,
+      file_output_dir: serverConfig.file_output_dir,
     };
     invoke("save_config", { conf: appConfig })
       .then(() => {
@@ -12,7 +12,11 @@ interface ServerFormProps {
 }
 
 const ServerForm = (props: ServerFormProps) => {
-  const conf = props.conf ?? { addr: "localhost", port: 8070 };
+  const conf = props.conf ?? {
+    addr: "localhost",
+    port: 8070,
+    file_output_dir: null,
+  };
 
   const addrInput = (e: Event) => {
     if (e.target) {
@@ -29,6 +33,16 @@ const ServerForm = (props: ServerFormProps) => {
     }
   };
 
+  const fileOutputDirInput = (e: Event) => {
+    if (e.target) {
+      const dir = (e.target as HTMLInputElement).value;
+      props.onChange({
+        ...conf,
+        file_output_dir: dir.length === 0 ? null : dir,
+      });
+    }
+  };
+
   return (
     <Fragment>
       <h2>Server</h2>
@@ -42,6 +56,15 @@ const ServerForm = (props: ServerFormProps) => {
         <input type="number" value={conf.port} onInput={portInput} />
       </label>
       <br />
+      <label>
+        Folder file actions can write to (leave blank to disable them):
+        <input
+          type="text"
+          value={conf.file_output_dir ?? ""}
+          onInput={fileOutputDirInput}
+        />
+      </label>
+      <br />
     </Fragment>
   );
 };
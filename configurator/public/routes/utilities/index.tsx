@@ -2,6 +2,7 @@ import { h } from "preact";
 import { useState } from "preact/hooks";
 
 import VTSAutoButtonGen from "./vtsautogen";
+import VTSModelPositions from "./vtsmodelpositions";
 
 const Utilities = () => {
   const [statusState, setStatusState] = useState<string>("");
@@ -22,6 +23,7 @@ const Utilities = () => {
         )}
       </p>
       <VTSAutoButtonGen msgFunc={setStatusState} />
+      <VTSModelPositions msgFunc={setStatusState} />
     </div>
   );
 };
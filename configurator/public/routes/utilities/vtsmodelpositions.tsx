@@ -0,0 +1,116 @@
+import { h, Fragment } from "preact";
+import { useState, useEffect, useCallback } from "preact/hooks";
+import { invoke } from "@tauri-apps/api/core";
+
+import sharedStyle from "/style.module.css";
+
+interface VTSModelPositionsProps {
+  msgFunc: (msg: string) => void;
+}
+
+const VTSModelPositions = ({ msgFunc }: VTSModelPositionsProps) => {
+  const [modelName, setModelName] = useState<string>("");
+  const [positionNames, setPositionNames] = useState<string[]>([]);
+  const [newName, setNewName] = useState<string>("");
+
+  const refresh = useCallback(() => {
+    const timer = setTimeout(() => {
+      msgFunc(
+        "Warning: A request to VTube Studio is taking an extended amount of time (there may be a pending authentication request that needs to be allowed)",
+      );
+    }, 1000);
+
+    Promise.all([
+      invoke("get_vts_current_model_name"),
+      invoke("get_vts_model_position_names"),
+    ])
+      .then(([nameRaw, listRaw]) => {
+        clearTimeout(timer);
+        setModelName(nameRaw as string);
+        setPositionNames(listRaw as string[]);
+      })
+      .catch((err) => {
+        clearTimeout(timer);
+        msgFunc(`Error occurred: ${err.toString()}`);
+      });
+  }, [msgFunc]);
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  const save = () => {
+    if (newName.length === 0) {
+      msgFunc("Position name cannot be empty");
+      return;
+    }
+
+    invoke("save_vts_model_position", { name: newName })
+      .then(() => {
+        msgFunc(`Saved the current position of ${modelName} as ${newName}`);
+        setNewName("");
+        refresh();
+      })
+      .catch((err) => {
+        msgFunc(`Error occurred: ${err.toString()}`);
+      });
+  };
+
+  const remove = (name: string) => {
+    invoke("delete_vts_model_position", { name })
+      .then(() => {
+        refresh();
+      })
+      .catch((err) => {
+        msgFunc(`Error occurred: ${err.toString()}`);
+      });
+  };
+
+  const onNewNameInput = (e: Event) => {
+    if (e.target) {
+      setNewName((e.target as HTMLInputElement).value);
+    }
+  };
+
+  return (
+    <Fragment>
+      <h2>Stored VTube Studio model positions</h2>
+      <p>
+        Positions are stored separately for every model and are used by the{" "}
+        "Restore Model Position From" action. Move the model in VTube Studio,{" "}
+        then save its current position under a name. Changes are saved{" "}
+        immediately.
+      </p>
+      <p>Current model: {modelName}</p>
+      <ul>
+        {positionNames.map((name) => (
+          <li key={name}>
+            {name}{" "}
+            <button type="button" onClick={() => remove(name)}>
+              Delete
+            </button>
+          </li>
+        ))}
+      </ul>
+      <form>
+        <label>
+          Position name:
+          <input
+            type="text"
+            value={newName}
+            onInput={onNewNameInput}
+            class={newName.length === 0 ? sharedStyle.invalid : ""}
+          />
+        </label>
+        <button type="button" onClick={save}>
+          Save Current Position
+        </button>
+        <button type="button" onClick={refresh}>
+          Refresh
+        </button>
+      </form>
+    </Fragment>
+  );
+};
+
+export default VTSModelPositions;
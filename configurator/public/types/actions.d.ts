@@ -5,19 +5,34 @@
 export * from "./obs";
 export * from "./vts";
 export * from "./general";
-
-export type PluginAction = OBSAction | VTSAction | GeneralAction;
-
-export type SingleActionTag = "OBS" | "VTS" | "General";
+export * from "./osc";
+export * from "./mqtt";
+export * from "./socket";
+
+export type PluginAction =
+  | OBSAction
+  | VTSAction
+  | OSCAction
+  | MQTTAction
+  | SocketAction
+  | GeneralAction;
+
+export type SingleActionTag =
+  | "OBS"
+  | "VTS"
+  | "OSC"
+  | "MQTT"
+  | "Socket"
+  | "General";
 
 export type SingleAction = {
   tag: SingleActionTag;
   content: PluginAction;
 };
 
-export type PluginQueryContent = OBSQuery | VTSQuery;
+export type PluginQueryContent = OBSQuery | VTSQuery | MQTTQuery | GeneralQuery;
 
-export type PluginQueryTag = "OBS" | "VTS";
+export type PluginQueryTag = "OBS" | "VTS" | "MQTT" | "General";
 
 export type PluginQuery = {
   tag: PluginQueryTag;
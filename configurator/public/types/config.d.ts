@@ -13,8 +13,34 @@ export type VTSConfigData = {
   token_file: string;
 };
 
+// targets maps a name to a "host:port" address
+export type OSCConfigData = {
+  targets: Record<string, string>;
+  listen_addr: string | null;
+};
+
+// client_id null means a unique ID is generated on every start
+export type MQTTConfigData = {
+  host: string;
+  port: number;
+  client_id: string | null;
+  username: string | null;
+  password: string | null;
+  subscriptions: string[];
+};
+
+// WebSocket takes a URL, Tcp and Udp take a "host:port" address
+export type SocketEndpoint =
+  | { WebSocket: string }
+  | { Tcp: string }
+  | { Udp: string };
+
+export type SocketConfigData = {
+  endpoints: Record<string, SocketEndpoint>;
+};
+
 /*
- * OBSConfig and VTSConfig are the JSON forms exported by Serde from the Rust side
+ * OBSConfig, VTSConfig etc are the JSON forms exported by Serde from the Rust side
  * These are externally tagged enum representations
  */
 export type OBSConfig = {
@@ -25,17 +51,37 @@ export type VTSConfig = {
   VTS: VTSConfigData;
 };
 
+export type OSCConfig = {
+  OSC: OSCConfigData;
+};
+
+export type MQTTConfig = {
+  MQTT: MQTTConfigData;
+};
+
+export type SocketConfig = {
+  Socket: SocketConfigData;
+};
+
 // The string part here is for the "General" plugin, which has no configuration other than enable/disable
-export type PluginConfig = OBSConfig | VTSConfig | string;
+export type PluginConfig =
+  | OBSConfig
+  | VTSConfig
+  | OSCConfig
+  | MQTTConfig
+  | SocketConfig
+  | string;
 
 // Equivalent of the AppConfig struct in the Rust side
 export type AppConfig = {
   addr: string;
   port: number;
   plugins: PluginConfig[];
+  file_output_dir: string | null;
 };
 
 export type ServerConfig = {
   addr: string;
   port: number;
+  file_output_dir: string | null;
 };
@@ -1,4 +1,17 @@
-export type GeneralAction = GeneralActionDelay | GeneralActionRunCmd;
+export type GeneralAction =
+  | GeneralActionDelay
+  | GeneralActionRunCmd
+  | GeneralActionRunCmdWithOptions
+  | GeneralActionHttpRequest
+  | GeneralActionSetVariable
+  | GeneralActionWriteFile
+  | GeneralActionAppendLineToFile
+  | GeneralActionReplaceLineInFile
+  | GeneralActionIncrementFileCounter
+  | GeneralActionStartCountdown
+  | GeneralActionStartStopwatch
+  | GeneralActionTimer
+  | GeneralActionSchedule;
 
 export type GeneralActionDelay = {
   tag: "Delay";
@@ -11,3 +24,126 @@ export type GeneralActionRunCmd = {
   tag: "RunCommand";
   content: GeneralActionCommand;
 };
+
+// success_codes null means the exit code is ignored
+export type GeneralRunCommandData = {
+  cmd: string;
+  args: string[];
+  current_dir: string | null;
+  env: Record<string, string>;
+  timeout_sec: number | null;
+  success_codes: number[] | null;
+  stdout_var: string | null;
+  stderr_var: string | null;
+  detached: boolean;
+};
+
+export type GeneralActionRunCmdWithOptions = {
+  tag: "RunCommandWithOptions";
+  content: GeneralRunCommandData;
+};
+
+// variable name and the value to store, which can reference other variables using {{name}}
+export type GeneralActionSetVariable = {
+  tag: "SetVariable";
+  content: [string, string];
+};
+
+// expected_status null accepts any 2xx status
+export type GeneralHttpRequestData = {
+  method: string;
+  url: string;
+  headers: Record<string, string>;
+  body: unknown;
+  timeout_sec: number | null;
+  expected_status: number[] | null;
+  json_pointer: string | null;
+  response_var: string | null;
+};
+
+export type GeneralActionHttpRequest = {
+  tag: "HttpRequest";
+  content: GeneralHttpRequestData;
+};
+
+// path is relative to file_output_dir in the app config
+export type GeneralFileWriteData = {
+  path: string;
+  text: string;
+};
+
+export type GeneralActionWriteFile = {
+  tag: "WriteFile";
+  content: GeneralFileWriteData;
+};
+
+export type GeneralActionAppendLineToFile = {
+  tag: "AppendLineToFile";
+  content: GeneralFileWriteData;
+};
+
+// line starts at 1
+export type GeneralFileReplaceLineData = {
+  path: string;
+  line: number;
+  text: string;
+};
+
+export type GeneralActionReplaceLineInFile = {
+  tag: "ReplaceLineInFile";
+  content: GeneralFileReplaceLineData;
+};
+
+export type GeneralFileCounterData = {
+  path: string;
+  amount: number;
+  counter_var: string | null;
+};
+
+export type GeneralActionIncrementFileCounter = {
+  tag: "IncrementFileCounter";
+  content: GeneralFileCounterData;
+};
+
+// output_file is relative to file_output_dir, and gets the timer value written to it
+export type GeneralCountdownData = {
+  name: string;
+  duration_sec: number;
+  output_file: string | null;
+};
+
+export type GeneralActionStartCountdown = {
+  tag: "StartCountdown";
+  content: GeneralCountdownData;
+};
+
+export type GeneralStopwatchData = {
+  name: string;
+  output_file: string | null;
+};
+
+export type GeneralActionStartStopwatch = {
+  tag: "StartStopwatch";
+  content: GeneralStopwatchData;
+};
+
+// Takes the timer name
+export type GeneralActionTimer = {
+  tag: "StopTimer" | "ResumeTimer" | "ResetTimer";
+  content: string;
+};
+
+// Takes the name of a schedule in schedules.json
+export type GeneralActionSchedule = {
+  tag: "EnableSchedule" | "DisableSchedule" | "ToggleSchedule";
+  content: string;
+};
+
+export type GeneralQuery =
+  | { Variable: string }
+  | { HttpRequest: GeneralHttpRequestData }
+  | { TimerRunning: string }
+  | { TimerFinished: string }
+  | { TimerSeconds: string }
+  | { TimerText: string }
+  | { ScheduleEnabled: string };
@@ -0,0 +1,14 @@
+// qos is 0, 1 or 2
+export type MQTTPublishData = {
+  topic: string;
+  payload: string;
+  qos: number;
+  retain: boolean;
+};
+
+export type MQTTAction = {
+  tag: "Publish";
+  content: MQTTPublishData;
+};
+
+export type MQTTQuery = { LastValue: string };
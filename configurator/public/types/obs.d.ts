@@ -1,20 +1,105 @@
 export type OBSActionTag =
   | "ProgramSceneChange"
+  | "SceneCollectionChange"
+  | "ProfileChange"
   | "StartStream"
   | "StopStream"
   | "StartRecord"
   | "StopRecord"
+  | "SetInputText"
+  | "SetInputSettings"
+  | "MediaPlay"
+  | "MediaPause"
+  | "MediaStop"
+  | "MediaRestart"
+  | "MediaSeek"
+  | "WaitForMediaEnd"
+  | "SaveScreenshot"
+  | "TriggerHotkey"
+  | "VendorRequest"
   | "CheckConnection";
 
-// content is just the target scene for ProgramSceneChange or nothing for the rest
+// text can reference runtime variables using {{name}}
+export type OBSSetInputTextData = {
+  input: string;
+  text: string;
+};
+
+// any string in settings can reference runtime variables using {{name}}
+export type OBSSetInputSettingsData = {
+  input: string;
+  settings: object;
+  overlay: boolean;
+};
+
+export type OBSMediaSeekData = {
+  input: string;
+  time_sec: number;
+};
+
+export type OBSWaitForMediaEndData = {
+  input: string;
+  timeout_sec: number | null;
+};
+
+// source is null for the current program scene
+// path can reference runtime variables using {{name}}, and {{timestamp}} for the current time
+export type OBSSaveScreenshotData = {
+  source: string | null;
+  path: string;
+  width: number | null;
+  height: number | null;
+};
+
+// any string in data can reference runtime variables using {{name}}
+// the response is stored as JSON in the response_var variable if set
+export type OBSVendorRequestData = {
+  vendor: string;
+  request_type: string;
+  data: object;
+  response_var: string | null;
+};
+
+export type OBSActionData =
+  | string
+  | OBSSetInputTextData
+  | OBSSetInputSettingsData
+  | OBSMediaSeekData
+  | OBSWaitForMediaEndData
+  | OBSSaveScreenshotData
+  | OBSVendorRequestData;
+
+// content is the target scene/collection/profile for ProgramSceneChange, SceneCollectionChange
+// and ProfileChange, the hotkey name for TriggerHotkey, the input name for the media actions,
+// the matching data object for the actions taking more than one parameter or nothing for the rest
 export type OBSAction = {
   tag: OBSActionTag;
-  content?: string;
+  content?: OBSActionData;
 };
 
+// These take the input name
+export type OBSMediaQuery =
+  | { MediaState: string }
+  | { MediaCursor: string }
+  | { MediaDuration: string };
+
 // IsStreaming and IsRecording both returh "true" and "false" as strings
+// The timecodes are in the HH:MM:SS format
 export type OBSQuery =
   | "CurrentProgramScene"
   | "IsStreaming"
   | "IsRecording"
-  | "Version";
+  | "Version"
+  | "CurrentSceneCollection"
+  | "CurrentProfile"
+  | "StreamTimecode"
+  | "StreamDroppedFrames"
+  | "StreamTotalFrames"
+  | "StreamCongestion"
+  | "StreamBytes"
+  | "RecordTimecode"
+  | "CpuUsage"
+  | "ActiveFps"
+  | "RenderSkippedFrames"
+  | "OutputSkippedFrames"
+  | OBSMediaQuery;
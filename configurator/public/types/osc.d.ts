@@ -0,0 +1,17 @@
+export type OSCArg =
+  | { tag: "Int"; content: number }
+  | { tag: "Float"; content: number }
+  | { tag: "String"; content: string }
+  | { tag: "Bool"; content: boolean };
+
+// target is the name of one of the targets in the OSC config
+export type OSCSendData = {
+  target: string;
+  address: string;
+  args: OSCArg[];
+};
+
+export type OSCAction = {
+  tag: "Send";
+  content: OSCSendData;
+};
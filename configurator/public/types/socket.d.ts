@@ -0,0 +1,22 @@
+// endpoint is the name of one of the endpoints in the socket config
+export type SocketSendTextData = {
+  endpoint: string;
+  text: string;
+};
+
+export type SocketSendJsonData = {
+  endpoint: string;
+  json: unknown;
+};
+
+export type SocketActionSendText = {
+  tag: "SendText";
+  content: SocketSendTextData;
+};
+
+export type SocketActionSendJson = {
+  tag: "SendJson";
+  content: SocketSendJsonData;
+};
+
+export type SocketAction = SocketActionSendText | SocketActionSendJson;
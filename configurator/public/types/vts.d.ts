@@ -1,9 +1,11 @@
+// null fields are left unchanged
 export type VTSMoveModelData = {
-  x: number;
-  y: number;
-  rotation: number;
-  size: number;
+  x: number | null;
+  y: number | null;
+  rotation: number | null;
+  size: number | null;
   time_sec: number;
+  relative?: boolean;
 };
 
 export type VTSRestoreModelPositionData = {
@@ -11,10 +13,102 @@ export type VTSRestoreModelPositionData = {
   time_sec: number;
 };
 
+export type VTSParameterValue = {
+  id: string;
+  value: number;
+  weight: number | null;
+};
+
+export type VTSInjectParametersData = {
+  parameters: VTSParameterValue[];
+  add: boolean;
+  duration_sec: number | null;
+};
+
+export type VTSCreateParameterData = {
+  name: string;
+  min: number;
+  max: number;
+  default_value: number;
+};
+
+export type VTSColorTintData = {
+  all: boolean;
+  names: string[];
+  name_contains: string[];
+  tags: string[];
+  tag_contains: string[];
+  r: number;
+  g: number;
+  b: number;
+  a: number;
+  mix: number | null;
+  rainbow: boolean;
+  reset_after_sec: number | null;
+};
+
+export type VTSPhysicsMultiplier = {
+  group_id: string;
+  strength: number | null;
+  wind: number | null;
+};
+
+export type VTSPhysicsOverrideData = {
+  strength: number | null;
+  wind: number | null;
+  multipliers: VTSPhysicsMultiplier[];
+  duration_sec: number;
+};
+
+export type VTSLoadItemData = {
+  file_name: string;
+  x: number;
+  y: number;
+  size: number;
+  rotation: number;
+  fade_time: number;
+  order: number | null;
+  flipped: boolean;
+  locked: boolean;
+  instance_var: string | null;
+};
+
+export type VTSUnloadItemsData = {
+  instance_ids: string[];
+  file_names: string[];
+};
+
+export type VTSPinItemData = {
+  instance_id: string;
+  art_mesh_id: string;
+  random_position: boolean;
+  angle: number | null;
+  size: number | null;
+};
+
+export type VTSItemAnimationData = {
+  instance_id: string;
+  framerate: number | null;
+  frame: number | null;
+  brightness: number | null;
+  opacity: number | null;
+  play: boolean | null;
+};
+
 export type VTSActionData =
   | string
+  | boolean
+  | string[]
   | VTSMoveModelData
-  | VTSRestoreModelPositionData;
+  | VTSRestoreModelPositionData
+  | VTSInjectParametersData
+  | VTSCreateParameterData
+  | VTSColorTintData
+  | VTSPhysicsOverrideData
+  | VTSLoadItemData
+  | VTSUnloadItemsData
+  | VTSPinItemData
+  | VTSItemAnimationData;
 
 export type VTSActionTag =
   | "ToggleExpression"
@@ -23,11 +117,31 @@ export type VTSActionTag =
   | "LoadModel"
   | "MoveModel"
   | "TriggerHotkey"
+  | "ToggleExpressionByName"
+  | "EnableExpressionByName"
+  | "DisableExpressionByName"
+  | "LoadModelByName"
+  | "TriggerHotkeyByName"
   | "SaveCurrentModelPosition"
   | "RestoreModelPosition"
+  | "DeleteModelPosition"
+  | "InjectParameters"
+  | "StopInjectingParameters"
+  | "CreateParameter"
+  | "ColorTint"
+  | "OverridePhysics"
+  | "StopOverridingPhysics"
+  | "SetNdiActive"
+  | "ToggleNdi"
+  | "LoadItem"
+  | "UnloadItems"
+  | "PinItem"
+  | "UnpinItem"
+  | "ControlItemAnimation"
   | "CheckConnection";
 
-// content for most is just a single string, or nothing for CheckConnection
+// content for most is just a single string, a list of parameter IDs for StopInjectingParameters,
+// or nothing for CheckConnection
 export type VTSAction = {
   tag: VTSActionTag;
   content?: VTSActionData;
@@ -36,4 +150,20 @@ export type VTSAction = {
 export type VTSQuery =
   | "ActiveModelId"
   | "StoredModelPositionExists"
-  | "Version";
+  | { ModelPositionExists: string }
+  | "Version"
+  | "PhysicsStrength"
+  | "PhysicsWind"
+  | "PhysicsEnabled"
+  | "PhysicsOverrideActive"
+  | "NdiActive"
+  | "SceneLightingActive"
+  | "SceneLightingColor"
+  | { ExpressionActive: string }
+  | "FaceFound"
+  | "ActiveModelName"
+  | "ItemCount"
+  | "ModelPositionX"
+  | "ModelPositionY"
+  | "ModelRotation"
+  | "ModelSize";
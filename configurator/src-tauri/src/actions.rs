@@ -90,7 +90,7 @@ pub async fn run_action(
     action: Action,
     plugins_data: tauri::State<'_, PluginStates>,
 ) -> Result<(), String> {
-    action.run(&plugins_data.plugins).await?;
+    action.run(&plugins_data).await?;
 
     Ok(())
 }
@@ -44,7 +44,7 @@ async fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .manage(app_mod::config::AppConfigState(conf.clone()))
-        .manage(PluginStates::init(conf.plugins).await)
+        .manage(PluginStates::init(&conf, &config_dir).await)
         .manage(app_mod::actions::ActionConfigState(Mutex::new(
             ActionConfig::from_file(&format!("{config_dir}/actions.json")),
         )))
@@ -54,12 +54,20 @@ async fn main() {
         .manage(app_mod::config::ConfigFolder(config_dir.clone()))
         .invoke_handler(tauri::generate_handler![
             app_mod::plugins::obs::get_obs_scenes,
+            app_mod::plugins::obs::get_obs_scene_collections,
+            app_mod::plugins::obs::get_obs_profiles,
             app_mod::plugins::obs::test_obs_connection,
             app_mod::plugins::vts::test_vts_connection,
             app_mod::plugins::vts::generate_buttons_for_hotkeys,
             app_mod::plugins::vts::get_vts_expression_names,
             app_mod::plugins::vts::get_vts_model_names,
             app_mod::plugins::vts::get_vts_hotkey_names,
+            app_mod::plugins::vts::get_vts_art_mesh_names,
+            app_mod::plugins::vts::get_vts_art_mesh_tags,
+            app_mod::plugins::vts::get_vts_current_model_name,
+            app_mod::plugins::vts::get_vts_model_position_names,
+            app_mod::plugins::vts::save_vts_model_position,
+            app_mod::plugins::vts::delete_vts_model_position,
             app_mod::plugins::vts::get_vts_current_model_pos,
             app_mod::plugins::vts::get_vts_expression_name_from_id,
             app_mod::plugins::vts::get_vts_model_name_from_id,
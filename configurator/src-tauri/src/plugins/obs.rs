@@ -20,6 +20,32 @@ pub async fn get_obs_scenes(
     Ok(scene_names)
 }
 
+#[tauri::command]
+pub async fn get_obs_scene_collections(
+    plugins_data: tauri::State<'_, PluginStates>,
+) -> Result<Vec<String>, String> {
+    let plugins = &plugins_data.plugins;
+
+    let Some(PluginInstance::OBS(obs)) = plugins.get(&PluginType::OBS) else {
+        return Err("OBS plugin not configured".to_string());
+    };
+
+    obs.lock().await.get_scene_collection_list().await
+}
+
+#[tauri::command]
+pub async fn get_obs_profiles(
+    plugins_data: tauri::State<'_, PluginStates>,
+) -> Result<Vec<String>, String> {
+    let plugins = &plugins_data.plugins;
+
+    let Some(PluginInstance::OBS(obs)) = plugins.get(&PluginType::OBS) else {
+        return Err("OBS plugin not configured".to_string());
+    };
+
+    obs.lock().await.get_profile_list().await
+}
+
 #[tauri::command]
 pub async fn test_obs_connection(conf: OBSConfig) -> Result<bool, ()> {
     let mut conn = OBSConnector::new(conf).await;
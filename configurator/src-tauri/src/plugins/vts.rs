@@ -25,17 +25,62 @@ pub async fn get_vts_current_model_pos(
         let (x, y, rotation, size) = vts.lock().await.get_current_model_position().await?;
 
         Ok(VTSMoveModelInput {
-            x,
-            y,
-            rotation,
-            size,
+            x: Some(x),
+            y: Some(y),
+            rotation: Some(rotation),
+            size: Some(size),
             time_sec: 0.0,
+            relative: false,
         })
     } else {
         Err("VTS plugin not configured".to_string())
     }
 }
 
+#[tauri::command]
+pub async fn get_vts_current_model_name(
+    plugins_data: tauri::State<'_, PluginStates>,
+) -> Result<String, String> {
+    let plugins = &plugins_data.plugins;
+
+    if let Some(PluginInstance::VTS(vts)) = plugins.get(&PluginType::VTS) {
+        let mut vts = vts.lock().await;
+        let id = vts.get_current_model_id().await?;
+        vts.get_model_name_from_id(&id).await
+    } else {
+        Err("VTS plugin not configured".to_string())
+    }
+}
+
+// Stored positions are per model, these all use the currently loaded model
+#[tauri::command]
+pub async fn save_vts_model_position(
+    name: &str,
+    plugins_data: tauri::State<'_, PluginStates>,
+) -> Result<(), String> {
+    let plugins = &plugins_data.plugins;
+
+    if let Some(PluginInstance::VTS(vts)) = plugins.get(&PluginType::VTS) {
+        vts.lock().await.save_current_model_position(name).await
+    } else {
+        Err("VTS plugin not configured".to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn delete_vts_model_position(
+    name: &str,
+    plugins_data: tauri::State<'_, PluginStates>,
+) -> Result<(), String> {
+    let plugins = &plugins_data.plugins;
+
+    if let Some(PluginInstance::VTS(vts)) = plugins.get(&PluginType::VTS) {
+        vts.lock().await.delete_model_position(name).await
+    } else {
+        Err("VTS plugin not configured".to_string())
+    }
+}
+
 #[tauri::command]
 pub async fn generate_buttons_for_hotkeys(
     model_id: Option<String>,
@@ -163,6 +208,46 @@ pub async fn get_vts_hotkey_names(
     }
 }
 
+#[tauri::command]
+pub async fn get_vts_model_position_names(
+    plugins_data: tauri::State<'_, PluginStates>,
+) -> Result<Vec<String>, String> {
+    let plugins = &plugins_data.plugins;
+
+    if let Some(PluginInstance::VTS(vts)) = plugins.get(&PluginType::VTS) {
+        vts.lock().await.get_model_position_name_list().await
+    } else {
+        Err("VTS plugin not configured".to_string())
+    }
+}
+
+// Art mesh names and tags are both for the currently loaded model
+#[tauri::command]
+pub async fn get_vts_art_mesh_names(
+    plugins_data: tauri::State<'_, PluginStates>,
+) -> Result<Vec<String>, String> {
+    let plugins = &plugins_data.plugins;
+
+    if let Some(PluginInstance::VTS(vts)) = plugins.get(&PluginType::VTS) {
+        Ok(vts.lock().await.get_art_mesh_list().await?.0)
+    } else {
+        Err("VTS plugin not configured".to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn get_vts_art_mesh_tags(
+    plugins_data: tauri::State<'_, PluginStates>,
+) -> Result<Vec<String>, String> {
+    let plugins = &plugins_data.plugins;
+
+    if let Some(PluginInstance::VTS(vts)) = plugins.get(&PluginType::VTS) {
+        Ok(vts.lock().await.get_art_mesh_list().await?.1)
+    } else {
+        Err("VTS plugin not configured".to_string())
+    }
+}
+
 // This also includes the name of each hotkey as the second part of the tuple
 async fn get_vts_hotkey_ids(
     model_id: Option<String>,
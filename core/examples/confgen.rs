@@ -61,10 +61,22 @@ fn main() {
 
     let plugins = vec![obs_setup(), vts_setup(), PluginConfig::General];
 
+    let output_dir = input_str(
+        "Enter the folder file actions are allowed to write to (leave blank to disable them)",
+        "",
+    );
+    let output_dir = output_dir.trim();
+    let file_output_dir = if output_dir.is_empty() {
+        None
+    } else {
+        Some(output_dir.to_string())
+    };
+
     let conf = AppConfig {
         addr,
         port,
         plugins,
+        file_output_dir,
     };
 
     println!(
@@ -0,0 +1,31 @@
+use scuffcommander_core::schedule::{Schedule, ScheduleConfig, ScheduleTime};
+
+fn main() {
+    let schedules = vec![
+        // 19:55 every day
+        Schedule {
+            name: "Pre-stream".to_string(),
+            time: ScheduleTime::Cron("55 19 * * *".to_string()),
+            action_id: "Waiting Scene".to_string(),
+            enabled: true,
+        },
+        // Only once
+        Schedule {
+            name: "Special stream".to_string(),
+            time: ScheduleTime::At("2030-01-01 00:00:00".to_string()),
+            action_id: "Desktop+VTS Scene".to_string(),
+            enabled: true,
+        },
+        // Every 5 minutes, but only after an EnableSchedule action enables it
+        Schedule {
+            name: "Scene rotation".to_string(),
+            time: ScheduleTime::Every(300.0),
+            action_id: "Scene Toggle".to_string(),
+            enabled: false,
+        },
+    ];
+
+    let conf = ScheduleConfig { schedules };
+
+    println!("{}", serde_json::to_string_pretty(&conf).unwrap());
+}
@@ -0,0 +1,55 @@
+use scuffcommander_core::plugins::general::GeneralEventType;
+use scuffcommander_core::plugins::obs::OBSEventType;
+use scuffcommander_core::plugins::vts::VTSEventType;
+use scuffcommander_core::plugins::PluginEventType;
+use scuffcommander_core::trigger::{Trigger, TriggerConfig};
+
+fn main() {
+    let triggers = vec![
+        // Any scene change
+        Trigger {
+            event: PluginEventType::OBS(OBSEventType::ProgramSceneChanged),
+            filter: None,
+            action_id: "Dual Expr Toggle".to_string(),
+            subject_var: None,
+        },
+        // Only when switching to the "Waiting" scene
+        Trigger {
+            event: PluginEventType::OBS(OBSEventType::ProgramSceneChanged),
+            filter: Some("Waiting".to_string()),
+            action_id: "Model Toggle".to_string(),
+            subject_var: None,
+        },
+        Trigger {
+            event: PluginEventType::OBS(OBSEventType::StreamStarted),
+            filter: None,
+            action_id: "Desktop+VTS Scene".to_string(),
+            subject_var: None,
+        },
+        // Switch away from the VTS scene while face tracking is lost
+        Trigger {
+            event: PluginEventType::VTS(VTSEventType::FaceLost),
+            filter: None,
+            action_id: "Waiting Scene".to_string(),
+            subject_var: None,
+        },
+        // Only keeps the variable up to date, so it can be used as {{current_model}}
+        Trigger {
+            event: PluginEventType::VTS(VTSEventType::ModelLoaded),
+            filter: None,
+            action_id: String::new(),
+            subject_var: Some("current_model".to_string()),
+        },
+        // When the "starting_soon" countdown (started with a StartCountdown action) reaches 0
+        Trigger {
+            event: PluginEventType::General(GeneralEventType::TimerFinished),
+            filter: Some("starting_soon".to_string()),
+            action_id: "Desktop+VTS Scene".to_string(),
+            subject_var: None,
+        },
+    ];
+
+    let conf = TriggerConfig { triggers };
+
+    println!("{}", serde_json::to_string_pretty(&conf).unwrap());
+}
@@ -0,0 +1,65 @@
+use scuffcommander_core::action::Condition;
+use scuffcommander_core::plugins::general::{GeneralHttpRequestInput, GeneralQuery};
+use scuffcommander_core::plugins::obs::OBSQuery;
+use scuffcommander_core::plugins::PluginQuery;
+use scuffcommander_core::watcher::{
+    WatchChange, WatchCondition, WatchRule, WatchThreshold, Watcher, WatcherConfig,
+};
+use std::collections::HashMap;
+
+fn main() {
+    let watchers = vec![
+        // Keeps {{song}} up to date and runs an action whenever it changes
+        Watcher {
+            name: "Now playing".to_string(),
+            interval_sec: 5.0,
+            rule: WatchRule::Change(WatchChange {
+                query: PluginQuery::General(GeneralQuery::HttpRequest(GeneralHttpRequestInput {
+                    method: "GET".to_string(),
+                    url: "http://localhost:9000/now-playing".to_string(),
+                    headers: HashMap::new(),
+                    body: None,
+                    timeout_sec: Some(2.0),
+                    expected_status: None,
+                    json_pointer: Some("/title".to_string()),
+                    response_var: None,
+                })),
+                action_id: "Dummy button".to_string(),
+            }),
+            value_var: Some("song".to_string()),
+        },
+        // Only after the stream has been live for 2 checks in a row
+        Watcher {
+            name: "Stream live".to_string(),
+            interval_sec: 2.0,
+            rule: WatchRule::Condition(WatchCondition {
+                condition: Condition {
+                    query: PluginQuery::OBS(OBSQuery::IsStreaming),
+                    target: "true".to_string(),
+                },
+                on_true: Some("Desktop+VTS Scene".to_string()),
+                on_false: Some("Waiting Scene".to_string()),
+                confirm_count: 2,
+            }),
+            value_var: None,
+        },
+        // Goes on at 30 or more and only goes off again at 20 or less
+        Watcher {
+            name: "Viewer count".to_string(),
+            interval_sec: 30.0,
+            rule: WatchRule::Threshold(WatchThreshold {
+                query: PluginQuery::General(GeneralQuery::Variable("viewers".to_string())),
+                rise: 30.0,
+                fall: 20.0,
+                on_true: Some("Model Toggle".to_string()),
+                on_false: Some("Model Toggle".to_string()),
+                confirm_count: 1,
+            }),
+            value_var: None,
+        },
+    ];
+
+    let conf = WatcherConfig { watchers };
+
+    println!("{}", serde_json::to_string_pretty(&conf).unwrap());
+}
@@ -2,8 +2,9 @@ use async_recursion::async_recursion;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::read_to_string;
+use std::sync::Arc;
 
-use crate::plugins::{PluginAction, PluginInstance, PluginQuery, PluginType};
+use crate::plugins::{PluginAction, PluginQuery, PluginStates};
 
 // See examples/actiongen.rs on how to generate the actions.json file
 #[derive(Serialize, Deserialize)]
@@ -26,6 +27,26 @@ impl ActionConfig {
             }
         })
     }
+
+    // Runs the action in the background so a slow action doesn't hold up the caller, failures are
+    // only logged
+    // source describes what ran the action in the log messages (e.g. "Triggered")
+    pub fn spawn(&self, action_id: &str, states: &Arc<PluginStates>, source: &str) {
+        let Some(action) = self.actions.get(action_id) else {
+            println!("{source} action {action_id} not configured");
+            return;
+        };
+
+        let action = action.clone();
+        let action_id = action_id.to_string();
+        let source = source.to_string();
+        let states = states.clone();
+        tokio::spawn(async move {
+            if let Err(e) = action.run(&states).await {
+                println!("{source} action {action_id} failed: {e}");
+            }
+        });
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -35,17 +56,8 @@ pub struct Condition {
 }
 
 impl Condition {
-    pub async fn check(
-        &self,
-        plugins: &HashMap<PluginType, PluginInstance>,
-    ) -> Result<bool, String> {
-        let plugin_type = self.query.get_required_type();
-
-        let Some(plugin) = plugins.get(&plugin_type) else {
-            return Err(format!("Plugin {plugin_type} not configured"));
-        };
-
-        Ok(self.query.get(plugin).await? == self.target)
+    pub async fn check(&self, states: &PluginStates) -> Result<bool, String> {
+        Ok(self.query.run(states).await? == self.target)
     }
 }
 
@@ -59,35 +71,32 @@ pub enum Action {
 }
 
 impl Action {
-    async fn run_single(
-        action: &PluginAction,
-        plugins: &HashMap<PluginType, PluginInstance>,
-    ) -> Result<(), String> {
+    async fn run_single(action: &PluginAction, states: &PluginStates) -> Result<(), String> {
         let plugin_type = action.get_required_type();
-        match plugins.get(&plugin_type) {
-            Some(p) => action.run(p).await,
+        match states.plugins.get(&plugin_type) {
+            Some(p) => action.run(p, &states.variables).await,
             None => Err(format!("Plugin {plugin_type} not configured")),
         }
     }
 
     #[async_recursion]
     #[must_use]
-    pub async fn run(&self, plugins: &HashMap<PluginType, PluginInstance>) -> Result<(), String> {
+    pub async fn run(&self, states: &PluginStates) -> Result<(), String> {
         match self {
-            Action::Single(action) => Action::run_single(action, plugins).await,
+            Action::Single(action) => Action::run_single(action, states).await,
             Action::Chain(actions) => {
                 for action in actions {
-                    if let Err(e) = action.run(plugins).await {
+                    if let Err(e) = action.run(states).await {
                         return Err(format!("Action chain failed: {e}"));
                     }
                 }
                 Ok(())
             }
             Action::If(cond, then, else_) => {
-                if cond.check(plugins).await? {
-                    then.run(plugins).await?;
+                if cond.check(states).await? {
+                    then.run(states).await?;
                 } else if let Some(else_action) = else_ {
-                    else_action.run(plugins).await?;
+                    else_action.run(states).await?;
                 }
                 Ok(())
             }
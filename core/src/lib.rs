@@ -1,6 +1,10 @@
 pub mod action;
 pub mod plugins;
+pub mod schedule;
+pub mod trigger;
 pub mod ui;
+pub mod variables;
+pub mod watcher;
 
 use serde::{Deserialize, Serialize};
 use std::fs::read_to_string;
@@ -13,6 +17,9 @@ pub struct AppConfig {
     pub addr: String,
     pub port: u16,
     pub plugins: Vec<PluginConfig>,
+    // Folder the file actions (WriteFile etc) are allowed to write to, they are disabled if unset
+    #[serde(default)]
+    pub file_output_dir: Option<String>,
 }
 
 impl AppConfig {
@@ -30,6 +37,7 @@ impl AppConfig {
                 addr: "localhost".to_string(),
                 port: 8080,
                 plugins: PluginConfig::get_default_vec(),
+                file_output_dir: None,
             }
         })
     }
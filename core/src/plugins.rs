@@ -1,20 +1,31 @@
 pub mod general;
+pub mod mqtt;
 pub mod obs;
+pub mod osc;
+pub mod socket;
 pub mod vts;
 
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use tokio::sync::Mutex;
+use tokio::sync::{broadcast, Mutex};
 
-use general::GeneralAction;
-use obs::{OBSAction, OBSConfig, OBSConnector, OBSQuery};
-use vts::{VTSAction, VTSConfig, VTSConnector, VTSQuery};
+use crate::variables::Variables;
+use crate::AppConfig;
+use general::{GeneralAction, GeneralEventType, GeneralQuery, GeneralState};
+use mqtt::{MQTTAction, MQTTConfig, MQTTConnector, MQTTEventType, MQTTQuery};
+use obs::{OBSAction, OBSConfig, OBSConnector, OBSEventType, OBSQuery};
+use osc::{OSCAction, OSCConfig, OSCConnector, OSCEventType};
+use socket::{SocketAction, SocketConfig, SocketConnector};
+use vts::{VTSAction, VTSConfig, VTSConnector, VTSEventType, VTSQuery};
 
 #[derive(Eq, Hash, PartialEq, Display, Serialize, Deserialize)]
 pub enum PluginType {
     OBS,
     VTS,
+    OSC,
+    MQTT,
+    Socket,
     General,
 }
 
@@ -22,7 +33,10 @@ pub enum PluginType {
 pub enum PluginInstance {
     OBS(Mutex<OBSConnector>),
     VTS(Mutex<VTSConnector>),
-    General,
+    OSC(Mutex<OSCConnector>),
+    MQTT(MQTTConnector),
+    Socket(Mutex<SocketConnector>),
+    General(GeneralState),
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -30,10 +44,12 @@ pub enum PluginInstance {
 pub enum PluginQuery {
     OBS(OBSQuery),
     VTS(VTSQuery),
+    MQTT(MQTTQuery),
+    General(GeneralQuery),
 }
 
 impl PluginQuery {
-    pub async fn get(&self, plugin: &PluginInstance) -> Result<String, String> {
+    pub async fn get(&self, plugin: &PluginInstance, vars: &Variables) -> Result<String, String> {
         match (self, plugin) {
             (PluginQuery::OBS(query), PluginInstance::OBS(conn)) => {
                 query.run(&mut *conn.lock().await).await
@@ -41,15 +57,32 @@ impl PluginQuery {
             (PluginQuery::VTS(query), PluginInstance::VTS(conn)) => {
                 query.run(&mut *conn.lock().await).await
             }
+            (PluginQuery::MQTT(query), PluginInstance::MQTT(conn)) => query.run(conn).await,
+            (PluginQuery::General(query), PluginInstance::General(state)) => {
+                query.run(state, vars).await
+            }
             _ => Err("Mismatched action and plugin instance".to_string()),
         }
     }
 
+    // Looks up the plugin the query needs and gets the value from it
+    pub async fn run(&self, states: &PluginStates) -> Result<String, String> {
+        let plugin_type = self.get_required_type();
+
+        let Some(plugin) = states.plugins.get(&plugin_type) else {
+            return Err(format!("Plugin {plugin_type} not configured"));
+        };
+
+        self.get(plugin, &states.variables).await
+    }
+
     #[must_use]
     pub fn get_required_type(&self) -> PluginType {
         match self {
             PluginQuery::OBS(_) => PluginType::OBS,
             PluginQuery::VTS(_) => PluginType::VTS,
+            PluginQuery::MQTT(_) => PluginType::MQTT,
+            PluginQuery::General(_) => PluginType::General,
         }
     }
 }
@@ -59,19 +92,31 @@ impl PluginQuery {
 pub enum PluginAction {
     OBS(OBSAction),
     VTS(VTSAction),
+    OSC(OSCAction),
+    MQTT(MQTTAction),
+    Socket(SocketAction),
     General(GeneralAction),
 }
 
 impl PluginAction {
-    pub async fn run(&self, plugin: &PluginInstance) -> Result<(), String> {
+    pub async fn run(&self, plugin: &PluginInstance, vars: &Variables) -> Result<(), String> {
         match (self, plugin) {
-            (PluginAction::OBS(action), PluginInstance::OBS(conn)) => {
-                action.run(&mut *conn.lock().await).await
-            }
+            (PluginAction::OBS(action), PluginInstance::OBS(conn)) => action.run(conn, vars).await,
             (PluginAction::VTS(action), PluginInstance::VTS(conn)) => {
-                action.run(&mut *conn.lock().await).await
+                action.run(&mut *conn.lock().await, vars).await
+            }
+            (PluginAction::OSC(action), PluginInstance::OSC(conn)) => {
+                action.run(&mut *conn.lock().await, vars).await
+            }
+            (PluginAction::MQTT(action), PluginInstance::MQTT(conn)) => {
+                action.run(conn, vars).await
+            }
+            (PluginAction::Socket(action), PluginInstance::Socket(conn)) => {
+                action.run(&mut *conn.lock().await, vars).await
+            }
+            (PluginAction::General(action), PluginInstance::General(state)) => {
+                action.run(state, vars).await
             }
-            (PluginAction::General(action), PluginInstance::General) => action.run().await,
             _ => Err("Mismatched action and plugin instance".to_string()),
         }
     }
@@ -81,15 +126,53 @@ impl PluginAction {
         match self {
             PluginAction::OBS(_) => PluginType::OBS,
             PluginAction::VTS(_) => PluginType::VTS,
+            PluginAction::OSC(_) => PluginType::OSC,
+            PluginAction::MQTT(_) => PluginType::MQTT,
+            PluginAction::Socket(_) => PluginType::Socket,
             PluginAction::General(_) => PluginType::General,
         }
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(tag = "tag", content = "content")]
+pub enum PluginEventType {
+    OBS(OBSEventType),
+    VTS(VTSEventType),
+    OSC(OSCEventType),
+    MQTT(MQTTEventType),
+    General(GeneralEventType),
+}
+
+// Something that happened in a plugin, used to trigger actions (see trigger.rs)
+// subject is what the event is about if applicable (scene name, input name, etc)
+#[derive(Clone)]
+pub struct PluginEvent {
+    pub event_type: PluginEventType,
+    pub subject: Option<String>,
+}
+
+impl PluginEvent {
+    pub fn send(
+        sender: &broadcast::Sender<PluginEvent>,
+        event_type: PluginEventType,
+        subject: Option<String>,
+    ) {
+        // Only fails if nothing is listening, which is fine
+        let _ = sender.send(PluginEvent {
+            event_type,
+            subject,
+        });
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub enum PluginConfig {
     OBS(OBSConfig),
     VTS(VTSConfig),
+    OSC(OSCConfig),
+    MQTT(MQTTConfig),
+    Socket(SocketConfig),
     General,
 }
 
@@ -104,12 +187,15 @@ impl PluginConfig {
 // but it is both Send and Sync since the connectors have their own Mutex if they need one
 pub struct PluginStates {
     pub plugins: HashMap<PluginType, PluginInstance>,
+    pub variables: Variables,
+    pub events: broadcast::Sender<PluginEvent>,
 }
 
 impl PluginStates {
-    pub async fn init(conf: Vec<PluginConfig>) -> PluginStates {
+    // config_dir is where plugins keep any state that should persist between restarts
+    pub async fn init(conf: &AppConfig, config_dir: &str) -> PluginStates {
         let mut plugins = HashMap::new();
-        for plugin in conf {
+        for plugin in conf.plugins.clone() {
             match plugin {
                 PluginConfig::OBS(c) => {
                     plugins.insert(
@@ -118,17 +204,79 @@ impl PluginStates {
                     );
                 }
                 PluginConfig::VTS(c) => {
+                    let mut conn = VTSConnector::new(c).await;
+                    conn.load_position_store(format!("{config_dir}/vts_positions.json"))
+                        .await;
+
+                    plugins.insert(PluginType::VTS, PluginInstance::VTS(Mutex::new(conn)));
+                }
+                PluginConfig::OSC(c) => {
                     plugins.insert(
-                        PluginType::VTS,
-                        PluginInstance::VTS(Mutex::new(VTSConnector::new(c).await)),
+                        PluginType::OSC,
+                        PluginInstance::OSC(Mutex::new(OSCConnector::new(c).await)),
+                    );
+                }
+                PluginConfig::MQTT(c) => {
+                    plugins.insert(
+                        PluginType::MQTT,
+                        PluginInstance::MQTT(MQTTConnector::new(c)),
+                    );
+                }
+                PluginConfig::Socket(c) => {
+                    plugins.insert(
+                        PluginType::Socket,
+                        PluginInstance::Socket(Mutex::new(SocketConnector::new(c))),
                     );
                 }
                 PluginConfig::General => {
-                    plugins.insert(PluginType::General, PluginInstance::General);
+                    plugins.insert(
+                        PluginType::General,
+                        PluginInstance::General(GeneralState::new(conf.file_output_dir.clone())),
+                    );
                 }
             };
         }
 
-        PluginStates { plugins }
+        PluginStates {
+            plugins,
+            variables: Variables::default(),
+            events: broadcast::channel(100).0,
+        }
+    }
+
+    // Events are only listened for after this is called, use events.subscribe() to receive them
+    // event_types are the events used by triggers, plugins that can choose which events they get
+    // sent only ask for those
+    pub async fn start_event_listeners(&self, event_types: &[PluginEventType]) {
+        let vts_event_types: Vec<VTSEventType> = event_types
+            .iter()
+            .filter_map(|t| match t {
+                PluginEventType::VTS(t) => Some(t.clone()),
+                _ => None,
+            })
+            .collect();
+
+        for plugin in self.plugins.values() {
+            match plugin {
+                PluginInstance::OBS(conn) => {
+                    conn.lock().await.start_event_listener(self.events.clone());
+                }
+                PluginInstance::VTS(conn) => {
+                    conn.lock()
+                        .await
+                        .start_event_listener(self.events.clone(), &vts_event_types);
+                }
+                PluginInstance::OSC(conn) => {
+                    conn.lock().await.start_event_listener(self.events.clone());
+                }
+                PluginInstance::MQTT(conn) => {
+                    conn.start_event_listener(self.events.clone());
+                }
+                PluginInstance::General(state) => {
+                    state.start_event_listener(self.events.clone());
+                }
+                PluginInstance::Socket(_) => {}
+            }
+        }
     }
 }
@@ -1,14 +1,178 @@
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::process::Stdio;
+use std::sync::OnceLock;
+
 use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, Mutex};
+
+use crate::plugins::PluginEvent;
+use crate::schedule::ScheduleSwitches;
+use crate::variables::Variables;
+use timers::Timers;
+
+pub mod timers;
+
+// The subject of TimerFinished is the name of the countdown
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum GeneralEventType {
+    TimerFinished,
+}
+
+// The command, arguments and environment values can reference variables using {{name}}
+// env is added on top of the inherited environment
+// If the command takes longer than timeout_sec it is killed and the action fails
+// success_codes are the exit codes counted as success, None ignores the exit code
+// stdout_var and stderr_var store the output of the command (without trailing whitespace), stderr
+// is also included in the error if the exit code is not one of success_codes
+// Actions don't return anything on success, so the output is only available through the variables
+// detached commands are left running in the background (still killed after timeout_sec if set),
+// the output and exit code are ignored
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GeneralRunCommandInput {
+    pub cmd: String,
+    pub args: Vec<String>,
+    pub current_dir: Option<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    pub timeout_sec: Option<f64>,
+    pub success_codes: Option<Vec<i32>>,
+    pub stdout_var: Option<String>,
+    pub stderr_var: Option<String>,
+    #[serde(default)]
+    pub detached: bool,
+}
+
+// Shared so connections can be reused between requests, use http_client to get it
+static HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+fn http_client() -> &'static reqwest::Client {
+    HTTP_CLIENT.get_or_init(reqwest::Client::new)
+}
+
+// The url, header values and every string in the body can reference variables using {{name}}
+// The body is sent as JSON if set
+// expected_status lists the status codes counted as success, None accepts any 2xx code
+// If json_pointer is set (e.g. "/data/0/name") the response is parsed as JSON and only the value
+// it points to is kept, strings are stored without quotes
+// response_var stores the (possibly narrowed down) response body
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GeneralHttpRequestInput {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    pub body: Option<serde_json::Value>,
+    pub timeout_sec: Option<f64>,
+    pub expected_status: Option<Vec<u16>>,
+    pub json_pointer: Option<String>,
+    pub response_var: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub enum GeneralQuery {
+    // The value of a runtime variable, empty if it's not set
+    Variable(String),
+    // The response body (or the json_pointer value) of the request, response_var is ignored
+    HttpRequest(GeneralHttpRequestInput),
+    TimerRunning(String),
+    // Only countdowns finish
+    TimerFinished(String),
+    // Remaining seconds for countdowns, elapsed seconds for stopwatches
+    TimerSeconds(String),
+    // Same as TimerSeconds but formatted as MM:SS (or H:MM:SS)
+    TimerText(String),
+    ScheduleEnabled(String),
+}
+
+impl GeneralQuery {
+    pub async fn run(&self, state: &GeneralState, vars: &Variables) -> Result<String, String> {
+        match self {
+            GeneralQuery::Variable(name) => Ok(vars.get(name).await.unwrap_or_default()),
+            GeneralQuery::HttpRequest(data) => send_http_request(data, vars).await,
+            GeneralQuery::TimerRunning(name) => {
+                Ok(state.timers.get_status(name)?.running.to_string())
+            }
+            GeneralQuery::TimerFinished(name) => {
+                Ok(state.timers.get_status(name)?.finished.to_string())
+            }
+            GeneralQuery::TimerSeconds(name) => {
+                Ok(state.timers.get_status(name)?.seconds.to_string())
+            }
+            GeneralQuery::TimerText(name) => Ok(state.timers.get_status(name)?.text),
+            GeneralQuery::ScheduleEnabled(name) => Ok(state.schedules.get(name)?.to_string()),
+        }
+    }
+}
+
+// path is relative to file_output_dir in config.json, and both path and text can reference
+// variables using {{name}}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GeneralFileWriteInput {
+    pub path: String,
+    pub text: String,
+}
+
+// line starts at 1, and can be at most one past the last line (which adds the line at the end)
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GeneralFileReplaceLineInput {
+    pub path: String,
+    pub line: usize,
+    pub text: String,
+}
+
+// A missing or empty file counts as 0, counter_var stores the new value
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GeneralFileCounterInput {
+    pub path: String,
+    pub amount: i64,
+    pub counter_var: Option<String>,
+}
+
+// output_file is relative to file_output_dir like the file actions, and gets the current value
+// (MM:SS) written to it while the timer runs
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GeneralCountdownInput {
+    pub name: String,
+    pub duration_sec: f64,
+    pub output_file: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GeneralStopwatchInput {
+    pub name: String,
+    pub output_file: Option<String>,
+}
 
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(tag = "tag", content = "content")]
 pub enum GeneralAction {
     Delay(f64),
     RunCommand(String, Vec<String>, Option<String>),
+    RunCommandWithOptions(GeneralRunCommandInput),
+    HttpRequest(GeneralHttpRequestInput),
+    // Variable name and the (templated) value to store in it
+    SetVariable(String, String),
+    WriteFile(GeneralFileWriteInput),
+    // The text is added as a new line at the end of the file
+    AppendLineToFile(GeneralFileWriteInput),
+    ReplaceLineInFile(GeneralFileReplaceLineInput),
+    IncrementFileCounter(GeneralFileCounterInput),
+    // Starting a timer replaces any existing timer with the same name
+    StartCountdown(GeneralCountdownInput),
+    StartStopwatch(GeneralStopwatchInput),
+    StopTimer(String),
+    ResumeTimer(String),
+    // Goes back to the starting value without running
+    ResetTimer(String),
+    // Takes the name of a schedule in schedules.json
+    EnableSchedule(String),
+    DisableSchedule(String),
+    ToggleSchedule(String),
 }
 
 impl GeneralAction {
-    pub async fn run(&self) -> Result<(), String> {
+    pub async fn run(&self, state: &GeneralState, vars: &Variables) -> Result<(), String> {
         match self {
             GeneralAction::Delay(dur) => {
                 tokio::time::sleep(
@@ -31,8 +195,426 @@ impl GeneralAction {
                     .await
                     .map_err(|e| e.to_string())?;
             }
+            GeneralAction::RunCommandWithOptions(data) => run_command(data, vars).await?,
+            GeneralAction::HttpRequest(data) => {
+                let response = send_http_request(data, vars).await?;
+                if let Some(var) = &data.response_var {
+                    vars.set(var, response).await;
+                }
+            }
+            GeneralAction::SetVariable(name, value) => {
+                vars.set(name, vars.render(value).await).await;
+            }
+            GeneralAction::WriteFile(data) => {
+                let path = state.resolve_file_path(&vars.render(&data.path).await)?;
+                let text = vars.render(&data.text).await;
+                let _lock = state.file_lock.lock().await;
+                write_file(&path, text).await?;
+            }
+            GeneralAction::AppendLineToFile(data) => {
+                let path = state.resolve_file_path(&vars.render(&data.path).await)?;
+                let text = vars.render(&data.text).await;
+                let _lock = state.file_lock.lock().await;
+                let mut lines = read_file_lines(&path).await?;
+                lines.push(text);
+                write_file(&path, lines.join("\n") + "\n").await?;
+            }
+            GeneralAction::ReplaceLineInFile(data) => {
+                if data.line == 0 {
+                    return Err("Line numbers start at 1".to_string());
+                }
+
+                let path = state.resolve_file_path(&vars.render(&data.path).await)?;
+                let text = vars.render(&data.text).await;
+                let _lock = state.file_lock.lock().await;
+                let mut lines = read_file_lines(&path).await?;
+                if data.line > lines.len() + 1 {
+                    return Err(format!(
+                        "{} only has {} lines, can't replace line {}",
+                        path.display(),
+                        lines.len(),
+                        data.line
+                    ));
+                }
+                if data.line > lines.len() {
+                    lines.push(text);
+                } else {
+                    lines[data.line - 1] = text;
+                }
+                write_file(&path, lines.join("\n") + "\n").await?;
+            }
+            GeneralAction::IncrementFileCounter(data) => {
+                let path = state.resolve_file_path(&vars.render(&data.path).await)?;
+                let _lock = state.file_lock.lock().await;
+                let current = read_file_lines(&path).await?.join("\n");
+                let current = current.trim();
+                let value = if current.is_empty() {
+                    0
+                } else {
+                    current
+                        .parse::<i64>()
+                        .map_err(|_| format!("{} does not contain a number", path.display()))?
+                };
+
+                let value = value.saturating_add(data.amount).to_string();
+                write_file(&path, value.clone()).await?;
+                if let Some(var) = &data.counter_var {
+                    vars.set(var, value).await;
+                }
+            }
+            GeneralAction::StartCountdown(data) => {
+                let duration = std::time::Duration::try_from_secs_f64(data.duration_sec)
+                    .map_err(|e| e.to_string())?;
+                let output_file = state.resolve_output_file(&data.output_file, vars).await?;
+                state
+                    .timers
+                    .start_countdown(&data.name, duration, output_file);
+            }
+            GeneralAction::StartStopwatch(data) => {
+                let output_file = state.resolve_output_file(&data.output_file, vars).await?;
+                state.timers.start_stopwatch(&data.name, output_file);
+            }
+            GeneralAction::StopTimer(name) => state.timers.stop(name)?,
+            GeneralAction::ResumeTimer(name) => state.timers.resume(name)?,
+            GeneralAction::ResetTimer(name) => state.timers.reset(name)?,
+            GeneralAction::EnableSchedule(name) => state.schedules.set(name, true)?,
+            GeneralAction::DisableSchedule(name) => state.schedules.set(name, false)?,
+            GeneralAction::ToggleSchedule(name) => state.schedules.toggle(name)?,
         }
 
         Ok(())
     }
 }
+
+// Files can only be written inside file_output_dir (file actions fail if it isn't set)
+pub struct GeneralState {
+    file_output_dir: Option<PathBuf>,
+    // Held while a file action reads and writes, so actions running at the same time (from
+    // triggers, schedules or watchers) can't lose each other's changes
+    file_lock: Mutex<()>,
+    pub timers: Timers,
+    pub schedules: ScheduleSwitches,
+}
+
+impl GeneralState {
+    #[must_use]
+    pub fn new(file_output_dir: Option<String>) -> GeneralState {
+        GeneralState {
+            file_output_dir: file_output_dir.map(PathBuf::from),
+            file_lock: Mutex::new(()),
+            timers: Timers::default(),
+            schedules: ScheduleSwitches::default(),
+        }
+    }
+
+    pub fn start_event_listener(&self, sender: broadcast::Sender<PluginEvent>) {
+        self.timers.set_event_sender(sender);
+    }
+
+    async fn resolve_output_file(
+        &self,
+        path: &Option<String>,
+        vars: &Variables,
+    ) -> Result<Option<PathBuf>, String> {
+        match path {
+            Some(path) => Ok(Some(self.resolve_file_path(&vars.render(path).await)?)),
+            None => Ok(None),
+        }
+    }
+
+    // Only plain relative paths are allowed, and the folder the file is in is checked after
+    // resolving symlinks so a link can't point the write outside file_output_dir
+    fn resolve_file_path(&self, path: &str) -> Result<PathBuf, String> {
+        let Some(dir) = &self.file_output_dir else {
+            return Err(
+                "File actions are disabled, set file_output_dir in config.json".to_string(),
+            );
+        };
+        let dir = dir
+            .canonicalize()
+            .map_err(|e| format!("Unable to access {}: {e}", dir.display()))?;
+
+        let rel_path = Path::new(path);
+        if path.is_empty() {
+            return Err("The file path is empty".to_string());
+        }
+        if !rel_path
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+        {
+            return Err(format!(
+                "{path} is not a relative path inside file_output_dir"
+            ));
+        }
+
+        let full_path = dir.join(rel_path);
+        let parent = full_path
+            .parent()
+            .unwrap_or(&dir)
+            .canonicalize()
+            .map_err(|e| format!("Unable to access the folder of {path}: {e}"))?;
+        if !parent.starts_with(&dir) {
+            return Err(format!("{path} is not inside file_output_dir"));
+        }
+
+        let full_path = parent.join(full_path.file_name().unwrap_or_default());
+        if full_path.is_symlink() {
+            return Err(format!("{path} is a symlink"));
+        }
+
+        Ok(full_path)
+    }
+}
+
+// A missing file is treated as empty
+async fn read_file_lines(path: &Path) -> Result<Vec<String>, String> {
+    match tokio::fs::read_to_string(path).await {
+        Ok(content) => Ok(content.lines().map(str::to_string).collect()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("Unable to read {}: {e}", path.display())),
+    }
+}
+
+async fn write_file(path: &Path, content: String) -> Result<(), String> {
+    tokio::fs::write(path, content)
+        .await
+        .map_err(|e| format!("Unable to write {}: {e}", path.display()))
+}
+
+async fn run_command(data: &GeneralRunCommandInput, vars: &Variables) -> Result<(), String> {
+    let mut builder = tokio::process::Command::new(vars.render(&data.cmd).await);
+
+    for arg in &data.args {
+        builder.arg(vars.render(arg).await);
+    }
+
+    for (name, value) in &data.env {
+        builder.env(name, vars.render(value).await);
+    }
+
+    if let Some(current_dir) = &data.current_dir {
+        builder.current_dir(current_dir);
+    }
+
+    let timeout = data
+        .timeout_sec
+        .map(std::time::Duration::try_from_secs_f64)
+        .transpose()
+        .map_err(|e| e.to_string())?;
+
+    if data.detached {
+        let mut child = builder.spawn().map_err(|e| e.to_string())?;
+
+        tokio::spawn(async move {
+            match timeout {
+                Some(timeout) => {
+                    if tokio::time::timeout(timeout, child.wait()).await.is_err() {
+                        match child.kill().await {
+                            Ok(()) => println!("Detached command timed out and was killed"),
+                            Err(e) => println!("Unable to kill timed out detached command: {e}"),
+                        }
+                    }
+                }
+                None => {
+                    let _ = child.wait().await;
+                }
+            }
+        });
+
+        return Ok(());
+    }
+
+    // This is what kills the command on timeout, since the child is dropped along with the future
+    // Detached commands are left out so they keep running when the server stops
+    builder.kill_on_drop(true);
+
+    if data.stdout_var.is_some() {
+        builder.stdout(Stdio::piped());
+    }
+    if data.stderr_var.is_some() || data.success_codes.is_some() {
+        builder.stderr(Stdio::piped());
+    }
+
+    let output = builder
+        .spawn()
+        .map_err(|e| e.to_string())?
+        .wait_with_output();
+    let output = match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, output).await.map_err(|_| {
+            format!(
+                "Command timed out after {} seconds and was killed",
+                timeout.as_secs_f64()
+            )
+        })?,
+        None => output.await,
+    }
+    .map_err(|e| e.to_string())?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr)
+        .trim_end()
+        .to_string();
+
+    if let Some(var) = &data.stdout_var {
+        let stdout = String::from_utf8_lossy(&output.stdout)
+            .trim_end()
+            .to_string();
+        vars.set(var, stdout).await;
+    }
+    if let Some(var) = &data.stderr_var {
+        vars.set(var, stderr.clone()).await;
+    }
+
+    if let Some(success_codes) = &data.success_codes {
+        if !output
+            .status
+            .code()
+            .is_some_and(|code| success_codes.contains(&code))
+        {
+            return Err(if stderr.is_empty() {
+                format!("Command failed ({})", output.status)
+            } else {
+                format!("Command failed ({}): {stderr}", output.status)
+            });
+        }
+    }
+
+    Ok(())
+}
+
+async fn send_http_request(
+    data: &GeneralHttpRequestInput,
+    vars: &Variables,
+) -> Result<String, String> {
+    let method = reqwest::Method::from_bytes(data.method.to_uppercase().as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    let mut builder = http_client().request(method, vars.render(&data.url).await);
+
+    for (name, value) in &data.headers {
+        builder = builder.header(name, vars.render(value).await);
+    }
+
+    if let Some(body) = &data.body {
+        builder = builder
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(vars.render_json(body).await.to_string());
+    }
+
+    if let Some(timeout_sec) = data.timeout_sec {
+        builder = builder.timeout(
+            std::time::Duration::try_from_secs_f64(timeout_sec).map_err(|e| e.to_string())?,
+        );
+    }
+
+    let resp = builder.send().await.map_err(|e| e.to_string())?;
+    let status = resp.status();
+    let text = resp.text().await.map_err(|e| e.to_string())?;
+
+    let status_ok = match &data.expected_status {
+        Some(expected) => expected.contains(&status.as_u16()),
+        None => status.is_success(),
+    };
+    if !status_ok {
+        return Err(format!("Unexpected HTTP status {status}: {text}"));
+    }
+
+    let Some(pointer) = &data.json_pointer else {
+        return Ok(text);
+    };
+
+    let json: serde_json::Value = serde_json::from_str(&text).map_err(|e| e.to_string())?;
+    match json.pointer(pointer) {
+        Some(serde_json::Value::String(s)) => Ok(s.clone()),
+        Some(value) => Ok(value.to_string()),
+        None => Err(format!("Nothing found at {pointer} in the response")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A fresh output folder inside the temp dir, along with a folder next to it that counts as
+    // outside
+    fn setup(name: &str) -> (GeneralState, PathBuf, PathBuf) {
+        let root =
+            std::env::temp_dir().join(format!("scuffcommander-test-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let dir = root.join("output");
+        let outside = root.join("outside");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+
+        let state = GeneralState::new(Some(dir.to_string_lossy().to_string()));
+        (state, dir.canonicalize().unwrap(), outside)
+    }
+
+    #[test]
+    fn resolve_file_path_allows_relative_paths() {
+        let (state, dir, _) = setup("relative");
+        std::fs::create_dir(dir.join("sub")).unwrap();
+
+        assert_eq!(state.resolve_file_path("a.txt").unwrap(), dir.join("a.txt"));
+        assert_eq!(
+            state.resolve_file_path("sub/a.txt").unwrap(),
+            dir.join("sub").join("a.txt")
+        );
+    }
+
+    #[test]
+    fn resolve_file_path_rejects_parent_dir() {
+        let (state, _, _) = setup("parent");
+
+        assert!(state.resolve_file_path("../outside/a.txt").is_err());
+        assert!(state.resolve_file_path("sub/../../a.txt").is_err());
+        assert!(state.resolve_file_path("..").is_err());
+    }
+
+    #[test]
+    fn resolve_file_path_rejects_absolute_paths() {
+        let (state, dir, _) = setup("absolute");
+
+        assert!(state.resolve_file_path("/etc/passwd").is_err());
+        assert!(state
+            .resolve_file_path(&dir.join("a.txt").to_string_lossy())
+            .is_err());
+    }
+
+    #[test]
+    fn resolve_file_path_rejects_empty_path() {
+        let (state, _, _) = setup("empty");
+
+        assert!(state.resolve_file_path("").is_err());
+        assert!(state.resolve_file_path(".").is_err());
+    }
+
+    #[test]
+    fn resolve_file_path_fails_without_output_dir() {
+        let state = GeneralState::new(None);
+
+        assert!(state.resolve_file_path("a.txt").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolve_file_path_rejects_symlinked_parent() {
+        let (state, dir, outside) = setup("symlink-parent");
+        std::os::unix::fs::symlink(&outside, dir.join("link")).unwrap();
+
+        assert!(state.resolve_file_path("link/a.txt").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolve_file_path_rejects_symlinked_file() {
+        let (state, dir, outside) = setup("symlink-file");
+        std::fs::write(outside.join("a.txt"), "").unwrap();
+        std::os::unix::fs::symlink(outside.join("a.txt"), dir.join("a.txt")).unwrap();
+        // Also when the link points somewhere inside the folder
+        std::fs::write(dir.join("b.txt"), "").unwrap();
+        std::os::unix::fs::symlink(dir.join("b.txt"), dir.join("c.txt")).unwrap();
+
+        assert!(state.resolve_file_path("a.txt").is_err());
+        assert!(state.resolve_file_path("c.txt").is_err());
+    }
+}
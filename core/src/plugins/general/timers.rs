@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tokio::task::AbortHandle;
+
+use super::GeneralEventType;
+use crate::plugins::{PluginEvent, PluginEventType};
+
+enum TimerKind {
+    Countdown(Duration),
+    Stopwatch,
+}
+
+struct Timer {
+    kind: TimerKind,
+    // Set while running, elapsed only holds the time from before the last stop
+    started: Option<Instant>,
+    elapsed: Duration,
+    output_file: Option<PathBuf>,
+    ticker: Option<AbortHandle>,
+}
+
+impl Timer {
+    fn total_elapsed(&self) -> Duration {
+        self.elapsed + self.started.map(|s| s.elapsed()).unwrap_or_default()
+    }
+
+    fn finished(&self) -> bool {
+        match self.kind {
+            TimerKind::Countdown(duration) => self.total_elapsed() >= duration,
+            TimerKind::Stopwatch => false,
+        }
+    }
+
+    // Countdowns round up so they only show 0 once they're done
+    fn seconds(&self) -> u64 {
+        match self.kind {
+            TimerKind::Countdown(duration) => {
+                let remaining = duration.saturating_sub(self.total_elapsed());
+                remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0)
+            }
+            TimerKind::Stopwatch => self.total_elapsed().as_secs(),
+        }
+    }
+
+    // How long until the shown value changes
+    fn until_next_second(&self) -> Duration {
+        let sub_sec = match self.kind {
+            TimerKind::Countdown(duration) => {
+                duration.saturating_sub(self.total_elapsed()).subsec_nanos()
+            }
+            TimerKind::Stopwatch => 1_000_000_000 - self.total_elapsed().subsec_nanos(),
+        };
+
+        if sub_sec == 0 {
+            Duration::from_secs(1)
+        } else {
+            // Slightly past the change, so the value has actually changed when checked
+            Duration::from_nanos(u64::from(sub_sec)) + Duration::from_millis(1)
+        }
+    }
+
+    fn stop(&mut self) {
+        self.elapsed = self.total_elapsed();
+        self.started = None;
+        if let Some(ticker) = self.ticker.take() {
+            ticker.abort();
+        }
+    }
+
+    fn status(&self) -> TimerStatus {
+        TimerStatus {
+            seconds: self.seconds(),
+            text: format_seconds(self.seconds()),
+            running: self.started.is_some() && !self.finished(),
+            finished: self.finished(),
+            countdown: matches!(self.kind, TimerKind::Countdown(_)),
+        }
+    }
+}
+
+// MM:SS, or H:MM:SS once there are hours to show
+fn format_seconds(secs: u64) -> String {
+    let (hours, mins, secs) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{mins:02}:{secs:02}")
+    } else {
+        format!("{mins:02}:{secs:02}")
+    }
+}
+
+#[derive(Serialize, Clone)]
+pub struct TimerStatus {
+    pub seconds: u64,
+    pub text: String,
+    pub running: bool,
+    pub finished: bool,
+    pub countdown: bool,
+}
+
+// Running timers write their value to output_file (if set) every time it changes, which is what OBS
+// text sources using "Read from file" need
+// Cloning gives another handle to the same timers
+#[derive(Clone, Default)]
+pub struct Timers {
+    timers: Arc<std::sync::Mutex<HashMap<String, Timer>>>,
+    event_sender: Arc<OnceLock<broadcast::Sender<PluginEvent>>>,
+}
+
+impl Timers {
+    // TimerFinished events are only sent after this is called
+    pub fn set_event_sender(&self, sender: broadcast::Sender<PluginEvent>) {
+        let _ = self.event_sender.set(sender);
+    }
+
+    // Replaces any timer with the same name
+    pub fn start_countdown(&self, name: &str, duration: Duration, output_file: Option<PathBuf>) {
+        self.start(name, TimerKind::Countdown(duration), output_file);
+    }
+
+    pub fn start_stopwatch(&self, name: &str, output_file: Option<PathBuf>) {
+        self.start(name, TimerKind::Stopwatch, output_file);
+    }
+
+    fn start(&self, name: &str, kind: TimerKind, output_file: Option<PathBuf>) {
+        let mut timers = self.timers.lock().unwrap();
+        if let Some(mut old) = timers.remove(name) {
+            old.stop();
+        }
+
+        let mut timer = Timer {
+            kind,
+            started: Some(Instant::now()),
+            elapsed: Duration::ZERO,
+            output_file,
+            ticker: None,
+        };
+        timer.ticker = Some(self.spawn_ticker(name.to_string()));
+        timers.insert(name.to_string(), timer);
+    }
+
+    pub fn stop(&self, name: &str) -> Result<(), String> {
+        let mut timers = self.timers.lock().unwrap();
+        let timer = timers
+            .get_mut(name)
+            .ok_or_else(|| format!("Timer {name} does not exist"))?;
+
+        timer.stop();
+        Ok(())
+    }
+
+    pub fn resume(&self, name: &str) -> Result<(), String> {
+        let mut timers = self.timers.lock().unwrap();
+        let timer = timers
+            .get_mut(name)
+            .ok_or_else(|| format!("Timer {name} does not exist"))?;
+
+        if timer.started.is_none() && !timer.finished() {
+            timer.started = Some(Instant::now());
+            timer.ticker = Some(self.spawn_ticker(name.to_string()));
+        }
+        Ok(())
+    }
+
+    // Goes back to the starting value and stops, resume starts it again
+    pub fn reset(&self, name: &str) -> Result<(), String> {
+        let mut timers = self.timers.lock().unwrap();
+        let timer = timers
+            .get_mut(name)
+            .ok_or_else(|| format!("Timer {name} does not exist"))?;
+
+        timer.stop();
+        timer.elapsed = Duration::ZERO;
+        if let Some(path) = timer.output_file.clone() {
+            tokio::spawn(write_output(path, format_seconds(timer.seconds())));
+        }
+        Ok(())
+    }
+
+    pub fn get_status(&self, name: &str) -> Result<TimerStatus, String> {
+        self.timers
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(Timer::status)
+            .ok_or_else(|| format!("Timer {name} does not exist"))
+    }
+
+    pub fn get_status_list(&self) -> HashMap<String, TimerStatus> {
+        self.timers
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, timer)| (name.clone(), timer.status()))
+            .collect()
+    }
+
+    // Runs while the timer is running, keeping output_file up to date and sending TimerFinished
+    // when a countdown reaches 0
+    fn spawn_ticker(&self, name: String) -> AbortHandle {
+        let timers = self.timers.clone();
+        let event_sender = self.event_sender.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let (output, finished, wait) = {
+                    let mut timers = timers.lock().unwrap();
+                    let Some(timer) = timers.get_mut(&name) else {
+                        return;
+                    };
+
+                    let finished = timer.finished();
+                    if finished {
+                        // Not stop() since that would abort this task
+                        timer.elapsed = timer.total_elapsed();
+                        timer.started = None;
+                        timer.ticker = None;
+                    }
+
+                    let output = timer
+                        .output_file
+                        .clone()
+                        .map(|path| (path, format_seconds(timer.seconds())));
+                    (output, finished, timer.until_next_second())
+                };
+
+                if let Some((path, text)) = output {
+                    write_output(path, text).await;
+                }
+
+                if finished {
+                    if let Some(sender) = event_sender.get() {
+                        PluginEvent::send(
+                            sender,
+                            PluginEventType::General(GeneralEventType::TimerFinished),
+                            Some(name),
+                        );
+                    }
+                    return;
+                }
+
+                tokio::time::sleep(wait).await;
+            }
+        })
+        .abort_handle()
+    }
+}
+
+async fn write_output(path: PathBuf, text: String) {
+    if let Err(e) = tokio::fs::write(&path, text).await {
+        println!("Unable to write timer to {}: {e}", path.display());
+    }
+}
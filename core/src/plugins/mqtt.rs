@@ -0,0 +1,219 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use rumqttc::{AsyncClient, Event, EventLoop, MqttOptions, Packet, QoS};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tokio::time::{sleep, Instant};
+
+use crate::plugins::{PluginEvent, PluginEventType};
+use crate::variables::Variables;
+
+// How long LastValue waits for the broker to send a retained message on a topic that hasn't been
+// seen yet
+const LAST_VALUE_WAIT: Duration = Duration::from_secs(2);
+
+// The subject of MessageReceived is the topic the message was published to
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum MQTTEventType {
+    MessageReceived,
+}
+
+// The topic and payload can reference variables using {{name}}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MQTTPublishInput {
+    pub topic: String,
+    pub payload: String,
+    #[serde(default)]
+    pub qos: u8,
+    #[serde(default)]
+    pub retain: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "tag", content = "content")]
+pub enum MQTTAction {
+    Publish(MQTTPublishInput),
+}
+
+impl MQTTAction {
+    pub async fn run(&self, conn: &MQTTConnector, vars: &Variables) -> Result<(), String> {
+        match self {
+            MQTTAction::Publish(data) => conn.publish(
+                vars.render(&data.topic).await,
+                vars.render(&data.payload).await,
+                data.qos,
+                data.retain,
+            ),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub enum MQTTQuery {
+    // Last payload published to the topic (including the broker's retained message)
+    LastValue(String),
+}
+
+impl MQTTQuery {
+    pub async fn run(&self, conn: &MQTTConnector) -> Result<String, String> {
+        match self {
+            MQTTQuery::LastValue(topic) => conn.get_last_value(topic).await,
+        }
+    }
+}
+
+// subscriptions are the topics (wildcards allowed) that send out MessageReceived events, so
+// messages on them can run actions using triggers.json
+// The server and configurator both connect using the same config, so without a client_id each
+// gets its own (brokers disconnect the older connection when two share an ID)
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MQTTConfig {
+    pub host: String,
+    pub port: u16,
+    pub client_id: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    #[serde(default)]
+    pub subscriptions: Vec<String>,
+}
+
+pub struct MQTTConnector {
+    client: AsyncClient,
+    connected: Arc<AtomicBool>,
+    topics: Arc<std::sync::Mutex<HashSet<String>>>,
+    last_values: Arc<std::sync::Mutex<HashMap<String, String>>>,
+    event_sender: Arc<OnceLock<broadcast::Sender<PluginEvent>>>,
+}
+
+impl MQTTConnector {
+    pub fn new(config: MQTTConfig) -> MQTTConnector {
+        let client_id = config
+            .client_id
+            .unwrap_or_else(|| format!("scuffcommander-{}", std::process::id()));
+        let mut opts = MqttOptions::new(client_id, config.host, config.port);
+        opts.set_keep_alive(Duration::from_secs(10));
+        if let Some(username) = config.username {
+            opts.set_credentials(username, config.password.unwrap_or_default());
+        }
+
+        let (client, event_loop) = AsyncClient::new(opts, 10);
+
+        let conn = MQTTConnector {
+            client,
+            connected: Arc::new(AtomicBool::new(false)),
+            topics: Arc::new(std::sync::Mutex::new(
+                config.subscriptions.into_iter().collect(),
+            )),
+            last_values: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            event_sender: Arc::new(OnceLock::new()),
+        };
+        conn.start_event_loop(event_loop);
+
+        conn
+    }
+
+    // Messages are always received (for LastValue), but only sent out as events after this is called
+    pub fn start_event_listener(&self, sender: broadcast::Sender<PluginEvent>) {
+        let _ = self.event_sender.set(sender);
+    }
+
+    // rumqttc reconnects on the next poll after an error, so this only has to wait between attempts
+    // Subscriptions are made again after every connect since the broker doesn't keep them
+    fn start_event_loop(&self, mut event_loop: EventLoop) {
+        let client = self.client.clone();
+        let connected = self.connected.clone();
+        let topics = self.topics.clone();
+        let last_values = self.last_values.clone();
+        let event_sender = self.event_sender.clone();
+
+        tokio::spawn(async move {
+            loop {
+                match event_loop.poll().await {
+                    Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                        println!("Connected to the MQTT broker");
+                        connected.store(true, Ordering::Relaxed);
+
+                        let topics = topics.lock().unwrap().clone();
+                        for topic in topics {
+                            if let Err(e) = client.try_subscribe(&topic, QoS::AtMostOnce) {
+                                println!("Unable to subscribe to MQTT topic {topic}: {e}");
+                            }
+                        }
+                    }
+                    Ok(Event::Incoming(Packet::Publish(publish))) => {
+                        let payload = String::from_utf8_lossy(&publish.payload).to_string();
+                        last_values
+                            .lock()
+                            .unwrap()
+                            .insert(publish.topic.clone(), payload);
+
+                        if let Some(sender) = event_sender.get() {
+                            PluginEvent::send(
+                                sender,
+                                PluginEventType::MQTT(MQTTEventType::MessageReceived),
+                                Some(publish.topic),
+                            );
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        if connected.swap(false, Ordering::Relaxed) {
+                            println!("Disconnected from the MQTT broker: {e}");
+                        }
+                        sleep(Duration::from_secs(5)).await;
+                    }
+                }
+            }
+        });
+    }
+
+    pub fn publish(
+        &self,
+        topic: String,
+        payload: String,
+        qos: u8,
+        retain: bool,
+    ) -> Result<(), String> {
+        if !self.connected.load(Ordering::Relaxed) {
+            return Err("Not connected to the MQTT broker".to_string());
+        }
+
+        let qos = rumqttc::qos(qos).map_err(|e| e.to_string())?;
+        self.client
+            .try_publish(topic, qos, retain, payload)
+            .map_err(|e| e.to_string())
+    }
+
+    // The first query on a topic subscribes to it, which makes the broker send its retained
+    // message (if there is one), after that the value is kept up to date
+    pub async fn get_last_value(&self, topic: &str) -> Result<String, String> {
+        // Only remembered once subscribing worked, so a failed attempt is tried again next time
+        let subscribed = self.topics.lock().unwrap().contains(topic);
+        if !subscribed {
+            if !self.connected.load(Ordering::Relaxed) {
+                return Err("Not connected to the MQTT broker".to_string());
+            }
+
+            self.client
+                .try_subscribe(topic, QoS::AtMostOnce)
+                .map_err(|e| e.to_string())?;
+            self.topics.lock().unwrap().insert(topic.to_string());
+        }
+
+        let deadline = Instant::now() + LAST_VALUE_WAIT;
+        loop {
+            if let Some(value) = self.last_values.lock().unwrap().get(topic) {
+                return Ok(value.clone());
+            }
+
+            if Instant::now() >= deadline {
+                return Err(format!("No value has been published to MQTT topic {topic}"));
+            }
+
+            sleep(Duration::from_millis(50)).await;
+        }
+    }
+}
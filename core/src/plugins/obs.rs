@@ -1,14 +1,47 @@
+use base64::Engine;
+use futures_util::StreamExt;
+use obws::common::MediaAction;
+use obws::events::{Event, OutputState};
+use obws::responses::general::Stats;
+use obws::responses::media_inputs::MediaState;
+use obws::responses::recording::RecordStatus;
 use obws::responses::scenes::Scene;
+use obws::responses::streaming::StreamStatus;
 use obws::Client;
 use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, Mutex};
+
+use crate::plugins::{PluginEvent, PluginEventType};
+use crate::variables::Variables;
+
+const MEDIA_START_GRACE: std::time::Duration = std::time::Duration::from_secs(1);
 
 // IsStreaming and IsRecording both return "true" or "false" as strings
+// The media queries take the input name, MediaState returns one of "None", "Playing", "Opening",
+// "Buffering", "Paused", "Stopped", "Ended", "Error" or "Unknown"
+// MediaCursor and MediaDuration return seconds, or an empty string if nothing is playing
+// The timecodes are in the HH:MM:SS format, CpuUsage is a percentage
 #[derive(Serialize, Deserialize, Clone)]
 pub enum OBSQuery {
     CurrentProgramScene,
     IsStreaming,
     IsRecording,
     Version,
+    CurrentSceneCollection,
+    CurrentProfile,
+    MediaState(String),
+    MediaCursor(String),
+    MediaDuration(String),
+    StreamTimecode,
+    StreamDroppedFrames,
+    StreamTotalFrames,
+    StreamCongestion,
+    StreamBytes,
+    RecordTimecode,
+    CpuUsage,
+    ActiveFps,
+    RenderSkippedFrames,
+    OutputSkippedFrames,
 }
 
 impl OBSQuery {
@@ -18,34 +51,306 @@ impl OBSQuery {
             OBSQuery::IsStreaming => conn.get_stream_status_string().await,
             OBSQuery::IsRecording => conn.get_record_status_string().await,
             OBSQuery::Version => conn.get_obs_version().await,
+            OBSQuery::CurrentSceneCollection => conn.get_current_scene_collection().await,
+            OBSQuery::CurrentProfile => conn.get_current_profile().await,
+            OBSQuery::MediaState(input) => conn.get_media_state_string(input).await,
+            OBSQuery::MediaCursor(input) => Ok(conn
+                .get_media_cursor(input)
+                .await?
+                .map_or_else(String::new, |c| c.to_string())),
+            OBSQuery::MediaDuration(input) => Ok(conn
+                .get_media_duration(input)
+                .await?
+                .map_or_else(String::new, |d| d.to_string())),
+            OBSQuery::StreamTimecode => {
+                Ok(format_timecode(conn.get_stream_stats().await?.timecode))
+            }
+            OBSQuery::StreamDroppedFrames => {
+                Ok(conn.get_stream_stats().await?.skipped_frames.to_string())
+            }
+            OBSQuery::StreamTotalFrames => {
+                Ok(conn.get_stream_stats().await?.total_frames.to_string())
+            }
+            OBSQuery::StreamCongestion => Ok(conn.get_stream_stats().await?.congestion.to_string()),
+            OBSQuery::StreamBytes => Ok(conn.get_stream_stats().await?.bytes.to_string()),
+            OBSQuery::RecordTimecode => {
+                Ok(format_timecode(conn.get_record_stats().await?.timecode))
+            }
+            OBSQuery::CpuUsage => Ok(conn.get_general_stats().await?.cpu_usage.to_string()),
+            OBSQuery::ActiveFps => Ok(conn.get_general_stats().await?.active_fps.to_string()),
+            OBSQuery::RenderSkippedFrames => Ok(conn
+                .get_general_stats()
+                .await?
+                .render_skipped_frames
+                .to_string()),
+            OBSQuery::OutputSkippedFrames => Ok(conn
+                .get_general_stats()
+                .await?
+                .output_skipped_frames
+                .to_string()),
         }
     }
 }
 
+// HH:MM:SS, like what OBS shows in its status bar
+fn format_timecode(duration: time::Duration) -> String {
+    let secs = duration.whole_seconds();
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+// Everything needed for a stream health overview, the timecodes are in the HH:MM:SS format
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OBSStats {
+    pub streaming: bool,
+    pub stream_timecode: String,
+    pub stream_congestion: f32,
+    pub stream_bytes: u64,
+    pub stream_dropped_frames: u32,
+    pub stream_total_frames: u32,
+    pub recording: bool,
+    pub record_timecode: String,
+    pub cpu_usage: f64,
+    pub memory_usage: f64,
+    pub active_fps: f64,
+    pub render_skipped_frames: u32,
+    pub render_total_frames: u32,
+    pub output_skipped_frames: u32,
+    pub output_total_frames: u32,
+}
+
+// text can reference runtime variables (see the variables module)
+// This works for both the GDI+ (Windows) and FreeType 2 text sources
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OBSSetInputTextInput {
+    pub input: String,
+    pub text: String,
+}
+
+// Any string within settings can reference runtime variables
+// If overlay is false then the input is reset to its defaults before applying the new settings
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OBSSetInputSettingsInput {
+    pub input: String,
+    pub settings: serde_json::Value,
+    pub overlay: bool,
+}
+
+// source is the name of the source or scene to capture, or None for the current program scene
+// path is templated and has to be an absolute path on the computer OBS is running on, the image
+// format is taken from its extension (for example "png" or "jpg")
+// If only one of width or height is set the aspect ratio is kept
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OBSSaveScreenshotInput {
+    pub source: Option<String>,
+    pub path: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+// For requests added by OBS plugins/scripts (Advanced Scene Switcher, Move Transition, etc)
+// Only vendor requests are supported, obws has no way to send other raw obs-websocket requests
+// Any string within data can reference runtime variables
+// If response_var is set the response data is stored in it as JSON
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OBSVendorRequestInput {
+    pub vendor: String,
+    pub request_type: String,
+    pub data: serde_json::Value,
+    pub response_var: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OBSMediaSeekInput {
+    pub input: String,
+    pub time_sec: f64,
+}
+
+// Waits until the media input stops playing (ended, stopped or nothing loaded)
+// OBS applies media actions in the background, so right after a restart the input can still show
+// the old ended state, which only counts once it has been seen playing or after MEDIA_START_GRACE
+// If timeout_sec is set and the media is still playing after that long the action fails
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OBSWaitForMediaEndInput {
+    pub input: String,
+    pub timeout_sec: Option<f64>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(tag = "tag", content = "content")]
 pub enum OBSAction {
     ProgramSceneChange(String),
+    SceneCollectionChange(String),
+    ProfileChange(String),
     StartStream,
     StopStream,
     StartRecord,
     StopRecord,
+    SetInputText(OBSSetInputTextInput),
+    SetInputSettings(OBSSetInputSettingsInput),
+    MediaPlay(String),
+    MediaPause(String),
+    MediaStop(String),
+    MediaRestart(String),
+    MediaSeek(OBSMediaSeekInput),
+    WaitForMediaEnd(OBSWaitForMediaEndInput),
+    SaveScreenshot(OBSSaveScreenshotInput),
+    TriggerHotkey(String),
+    VendorRequest(OBSVendorRequestInput),
     CheckConnection,
 }
 
 impl OBSAction {
-    pub async fn run(&self, conn: &mut OBSConnector) -> Result<(), String> {
+    pub async fn run(&self, conn: &Mutex<OBSConnector>, vars: &Variables) -> Result<(), String> {
+        match self {
+            // Only hold the connection between polls so other OBS actions can run while waiting
+            OBSAction::WaitForMediaEnd(data) => Self::wait_for_media_end(conn, data).await,
+            _ => self.run_locked(&mut *conn.lock().await, vars).await,
+        }
+    }
+
+    async fn wait_for_media_end(
+        conn: &Mutex<OBSConnector>,
+        data: &OBSWaitForMediaEndInput,
+    ) -> Result<(), String> {
+        let timeout = data
+            .timeout_sec
+            .map(std::time::Duration::try_from_secs_f64)
+            .transpose()
+            .map_err(|e| e.to_string())?;
+        let start = tokio::time::Instant::now();
+        let mut started = false;
+
+        loop {
+            match conn.lock().await.get_media_state(&data.input).await? {
+                MediaState::None | MediaState::Stopped | MediaState::Ended => {
+                    if started || start.elapsed() >= MEDIA_START_GRACE {
+                        return Ok(());
+                    }
+                }
+                MediaState::Error => {
+                    return Err(format!("Media input {} failed to play", data.input))
+                }
+                _ => started = true,
+            }
+
+            if timeout.is_some_and(|t| start.elapsed() >= t) {
+                return Err(format!("Timed out waiting for media input {}", data.input));
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+    }
+
+    async fn run_locked(&self, conn: &mut OBSConnector, vars: &Variables) -> Result<(), String> {
         match self {
             OBSAction::ProgramSceneChange(scene) => conn.change_current_program_scene(scene).await,
+            OBSAction::SceneCollectionChange(collection) => {
+                conn.change_current_scene_collection(collection).await
+            }
+            OBSAction::ProfileChange(profile) => conn.change_current_profile(profile).await,
             OBSAction::StartStream => conn.start_stream().await,
             OBSAction::StopStream => conn.stop_stream().await,
             OBSAction::StartRecord => conn.start_record().await,
             OBSAction::StopRecord => conn.stop_record().await,
+            OBSAction::SetInputText(data) => {
+                let settings = serde_json::json!({ "text": vars.render(&data.text).await });
+                conn.set_input_settings(&data.input, &settings, true).await
+            }
+            OBSAction::SetInputSettings(data) => {
+                let settings = vars.render_json(&data.settings).await;
+                conn.set_input_settings(&data.input, &settings, data.overlay)
+                    .await
+            }
+            OBSAction::MediaPlay(input) => {
+                conn.trigger_media_action(input, MediaAction::Play).await
+            }
+            OBSAction::MediaPause(input) => {
+                conn.trigger_media_action(input, MediaAction::Pause).await
+            }
+            OBSAction::MediaStop(input) => {
+                conn.trigger_media_action(input, MediaAction::Stop).await
+            }
+            OBSAction::MediaRestart(input) => {
+                conn.trigger_media_action(input, MediaAction::Restart).await
+            }
+            OBSAction::MediaSeek(data) => conn.set_media_cursor(&data.input, data.time_sec).await,
+            OBSAction::SaveScreenshot(data) => {
+                let path = vars.render(&data.path).await;
+                conn.save_screenshot(data.source.as_deref(), &path, data.width, data.height)
+                    .await
+            }
+            OBSAction::TriggerHotkey(name) => conn.trigger_hotkey(name).await,
+            OBSAction::VendorRequest(data) => {
+                let request_data = vars.render_json(&data.data).await;
+                let resp = conn
+                    .call_vendor_request(&data.vendor, &data.request_type, &request_data)
+                    .await?;
+
+                if let Some(var) = &data.response_var {
+                    vars.set(var, resp.to_string()).await;
+                }
+                Ok(())
+            }
+            OBSAction::WaitForMediaEnd(_) => {
+                Err("Waiting for media can't be done while holding the OBS connection".to_string())
+            }
             OBSAction::CheckConnection => conn.get_obs_version().await.map(|_| ()),
         }
     }
 }
 
+// The subject of ProgramSceneChanged is the new scene name and for InputMuted and InputUnmuted it
+// is the input name, the rest have no subject
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum OBSEventType {
+    ProgramSceneChanged,
+    StreamStarted,
+    StreamStopped,
+    RecordStarted,
+    RecordStopped,
+    InputMuted,
+    InputUnmuted,
+}
+
+impl OBSEventType {
+    // Returns None for the events that are not forwarded
+    fn from_obws_event(event: Event) -> Option<PluginEvent> {
+        let (event_type, subject) = match event {
+            Event::CurrentProgramSceneChanged { id } => {
+                (OBSEventType::ProgramSceneChanged, Some(id.name))
+            }
+            Event::StreamStateChanged { state, .. } => match state {
+                OutputState::Started => (OBSEventType::StreamStarted, None),
+                OutputState::Stopped => (OBSEventType::StreamStopped, None),
+                _ => return None,
+            },
+            Event::RecordStateChanged { state, .. } => match state {
+                OutputState::Started => (OBSEventType::RecordStarted, None),
+                OutputState::Stopped => (OBSEventType::RecordStopped, None),
+                _ => return None,
+            },
+            Event::InputMuteStateChanged { id, muted } => {
+                if muted {
+                    (OBSEventType::InputMuted, Some(id.name))
+                } else {
+                    (OBSEventType::InputUnmuted, Some(id.name))
+                }
+            }
+            _ => return None,
+        };
+
+        Some(PluginEvent {
+            event_type: PluginEventType::OBS(event_type),
+            subject,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct OBSConfig {
     pub addr: String,
@@ -68,6 +373,37 @@ impl OBSConnector {
         }
     }
 
+    // Uses a separate connection to OBS so events keep coming in regardless of what the main
+    // connection is doing, and retries every few seconds if OBS is not available
+    pub fn start_event_listener(&self, sender: broadcast::Sender<PluginEvent>) {
+        let config = self.config.clone();
+
+        tokio::spawn(async move {
+            loop {
+                match Client::connect(config.addr.clone(), config.port, config.password.clone())
+                    .await
+                {
+                    Ok(client) => match client.events() {
+                        Ok(events) => {
+                            println!("Listening for OBS events");
+                            let mut events = std::pin::pin!(events);
+                            while let Some(event) = events.next().await {
+                                if let Some(event) = OBSEventType::from_obws_event(event) {
+                                    PluginEvent::send(&sender, event.event_type, event.subject);
+                                }
+                            }
+                            println!("Lost connection to OBS, no longer receiving events");
+                        }
+                        Err(e) => println!("Unable to listen for OBS events: {e}"),
+                    },
+                    Err(e) => println!("Unable to connect to OBS for events: {e}"),
+                }
+
+                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            }
+        });
+    }
+
     async fn check_conn(&mut self) -> bool {
         if self.client.is_none() {
             self.client = Client::connect(
@@ -153,13 +489,129 @@ impl OBSConnector {
         }
     }
 
-    async fn get_stream_status(&mut self) -> Result<bool, String> {
+    pub async fn get_scene_collection_list(&mut self) -> Result<Vec<String>, String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .scene_collections()
+            .list()
+            .await
+        {
+            Ok(c) => Ok(c.collections),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    pub async fn get_current_scene_collection(&mut self) -> Result<String, String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .scene_collections()
+            .current()
+            .await
+        {
+            Ok(c) => Ok(c),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    // OBS only responds once the new collection has finished loading
+    pub async fn change_current_scene_collection(
+        &mut self,
+        collection: &str,
+    ) -> Result<(), String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .scene_collections()
+            .set_current(collection)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    pub async fn get_profile_list(&mut self) -> Result<Vec<String>, String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self.client.as_ref().unwrap().profiles().list().await {
+            Ok(p) => Ok(p.profiles),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    pub async fn get_current_profile(&mut self) -> Result<String, String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self.client.as_ref().unwrap().profiles().current().await {
+            Ok(p) => Ok(p),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    pub async fn change_current_profile(&mut self, profile: &str) -> Result<(), String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .profiles()
+            .set_current(profile)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    async fn get_stream_stats(&mut self) -> Result<StreamStatus, String> {
         if !self.check_conn().await {
             return Err("Unable to create OBS websocket connection".to_string());
         }
 
         match self.client.as_ref().unwrap().streaming().status().await {
-            Ok(res) => Ok(res.active),
+            Ok(res) => Ok(res),
             Err(e) => {
                 self.client = None;
                 Err(e.to_string())
@@ -167,13 +619,27 @@ impl OBSConnector {
         }
     }
 
-    async fn get_record_status(&mut self) -> Result<bool, String> {
+    async fn get_record_stats(&mut self) -> Result<RecordStatus, String> {
         if !self.check_conn().await {
             return Err("Unable to create OBS websocket connection".to_string());
         }
 
         match self.client.as_ref().unwrap().recording().status().await {
-            Ok(res) => Ok(res.active),
+            Ok(res) => Ok(res),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    async fn get_general_stats(&mut self) -> Result<Stats, String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self.client.as_ref().unwrap().general().stats().await {
+            Ok(res) => Ok(res),
             Err(e) => {
                 self.client = None;
                 Err(e.to_string())
@@ -181,6 +647,38 @@ impl OBSConnector {
         }
     }
 
+    pub async fn get_stats(&mut self) -> Result<OBSStats, String> {
+        let stream = self.get_stream_stats().await?;
+        let record = self.get_record_stats().await?;
+        let general = self.get_general_stats().await?;
+
+        Ok(OBSStats {
+            streaming: stream.active,
+            stream_timecode: format_timecode(stream.timecode),
+            stream_congestion: stream.congestion,
+            stream_bytes: stream.bytes,
+            stream_dropped_frames: stream.skipped_frames,
+            stream_total_frames: stream.total_frames,
+            recording: record.active,
+            record_timecode: format_timecode(record.timecode),
+            cpu_usage: general.cpu_usage,
+            memory_usage: general.memory_usage,
+            active_fps: general.active_fps,
+            render_skipped_frames: general.render_skipped_frames,
+            render_total_frames: general.render_total_frames,
+            output_skipped_frames: general.output_skipped_frames,
+            output_total_frames: general.output_total_frames,
+        })
+    }
+
+    async fn get_stream_status(&mut self) -> Result<bool, String> {
+        Ok(self.get_stream_stats().await?.active)
+    }
+
+    async fn get_record_status(&mut self) -> Result<bool, String> {
+        Ok(self.get_record_stats().await?.active)
+    }
+
     // Returns "true" or "false" as strings for the condition query system
     pub async fn get_stream_status_string(&mut self) -> Result<String, String> {
         let status = self.get_stream_status().await?;
@@ -258,4 +756,291 @@ impl OBSConnector {
             }
         }
     }
+
+    pub async fn set_input_settings(
+        &mut self,
+        input: &str,
+        settings: &serde_json::Value,
+        overlay: bool,
+    ) -> Result<(), String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .inputs()
+            .set_settings(obws::requests::inputs::SetSettings {
+                input: input.into(),
+                settings,
+                overlay: Some(overlay),
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    async fn get_media_status(
+        &mut self,
+        input: &str,
+    ) -> Result<obws::responses::media_inputs::MediaStatus, String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .media_inputs()
+            .status(input.into())
+            .await
+        {
+            Ok(status) => Ok(status),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    pub async fn get_media_state(&mut self, input: &str) -> Result<MediaState, String> {
+        Ok(self.get_media_status(input).await?.state)
+    }
+
+    // Returns the state as a string for the condition query system
+    pub async fn get_media_state_string(&mut self, input: &str) -> Result<String, String> {
+        Ok(format!("{:?}", self.get_media_state(input).await?))
+    }
+
+    // In seconds, None if nothing is playing
+    pub async fn get_media_cursor(&mut self, input: &str) -> Result<Option<f64>, String> {
+        Ok(self
+            .get_media_status(input)
+            .await?
+            .cursor
+            .map(|c| c.as_seconds_f64()))
+    }
+
+    // In seconds, None if nothing is playing
+    pub async fn get_media_duration(&mut self, input: &str) -> Result<Option<f64>, String> {
+        Ok(self
+            .get_media_status(input)
+            .await?
+            .duration
+            .map(|d| d.as_seconds_f64()))
+    }
+
+    pub async fn trigger_media_action(
+        &mut self,
+        input: &str,
+        action: MediaAction,
+    ) -> Result<(), String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .media_inputs()
+            .trigger_action(input.into(), action)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    pub async fn set_media_cursor(&mut self, input: &str, time_sec: f64) -> Result<(), String> {
+        let Some(cursor) =
+            time::Duration::checked_seconds_f64(time_sec).filter(|_| time_sec >= 0.0)
+        else {
+            return Err(format!("Invalid seek time {time_sec}"));
+        };
+
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .media_inputs()
+            .set_cursor(input.into(), cursor)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    async fn get_source_or_program_scene(
+        &mut self,
+        source: Option<&str>,
+    ) -> Result<String, String> {
+        match source {
+            Some(s) => Ok(s.to_string()),
+            None => self.get_current_program_scene().await,
+        }
+    }
+
+    pub async fn save_screenshot(
+        &mut self,
+        source: Option<&str>,
+        path: &str,
+        width: Option<u32>,
+        height: Option<u32>,
+    ) -> Result<(), String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        let source = self.get_source_or_program_scene(source).await?;
+        let path = std::path::Path::new(path);
+        let format = path
+            .extension()
+            .map_or_else(|| "png".to_string(), |e| e.to_string_lossy().to_lowercase());
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .sources()
+            .save_screenshot(obws::requests::sources::SaveScreenshot {
+                source: source.as_str().into(),
+                format: &format,
+                width,
+                height,
+                compression_quality: None,
+                file_path: path,
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    // source is the same as in save_screenshot, format is the image format (for example "jpg")
+    // Returns the MIME type and the image data
+    pub async fn get_screenshot(
+        &mut self,
+        source: Option<&str>,
+        format: &str,
+        width: Option<u32>,
+        height: Option<u32>,
+    ) -> Result<(String, Vec<u8>), String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        let source = self.get_source_or_program_scene(source).await?;
+
+        let data_url = match self
+            .client
+            .as_ref()
+            .unwrap()
+            .sources()
+            .take_screenshot(obws::requests::sources::TakeScreenshot {
+                source: source.as_str().into(),
+                format,
+                width,
+                height,
+                compression_quality: None,
+            })
+            .await
+        {
+            Ok(d) => d,
+            Err(e) => {
+                self.client = None;
+                return Err(e.to_string());
+            }
+        };
+
+        // OBS returns a data URL, so data:image/png;base64,XXXX
+        let Some((mime, data)) = data_url
+            .strip_prefix("data:")
+            .and_then(|d| d.split_once(";base64,"))
+        else {
+            return Err("Unexpected screenshot data from OBS".to_string());
+        };
+
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .map_err(|e| e.to_string())?;
+
+        Ok((mime.to_string(), data))
+    }
+
+    // Takes the hotkey name used internally by OBS (for example "OBSBasic.StartStreaming")
+    pub async fn trigger_hotkey(&mut self, name: &str) -> Result<(), String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .hotkeys()
+            .trigger_by_name(name, None)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
+
+    // Returns the response data from the vendor
+    pub async fn call_vendor_request(
+        &mut self,
+        vendor: &str,
+        request_type: &str,
+        data: &serde_json::Value,
+    ) -> Result<serde_json::Value, String> {
+        if !self.check_conn().await {
+            return Err("Unable to create OBS websocket connection".to_string());
+        }
+
+        match self
+            .client
+            .as_ref()
+            .unwrap()
+            .general()
+            .call_vendor_request(obws::requests::general::CallVendorRequest {
+                vendor_name: vendor,
+                request_type,
+                request_data: data,
+            })
+            .await
+        {
+            Ok(resp) => Ok(resp.response_data),
+            Err(e) => {
+                self.client = None;
+                Err(e.to_string())
+            }
+        }
+    }
 }
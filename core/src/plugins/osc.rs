@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use rosc::{OscMessage, OscPacket, OscType};
+use serde::{Deserialize, Serialize};
+use tokio::net::UdpSocket;
+use tokio::sync::broadcast;
+
+use crate::plugins::{PluginEvent, PluginEventType};
+use crate::variables::Variables;
+
+// The subject of MessageReceived is the OSC address of the message
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum OSCEventType {
+    MessageReceived,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "tag", content = "content")]
+pub enum OSCArg {
+    Int(i32),
+    Float(f32),
+    // Can reference variables using {{name}}
+    String(String),
+    Bool(bool),
+}
+
+// target is the name of one of the targets in the OSC config
+// The address can reference variables using {{name}}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OSCSendInput {
+    pub target: String,
+    pub address: String,
+    pub args: Vec<OSCArg>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "tag", content = "content")]
+pub enum OSCAction {
+    Send(OSCSendInput),
+}
+
+impl OSCAction {
+    pub async fn run(&self, conn: &mut OSCConnector, vars: &Variables) -> Result<(), String> {
+        match self {
+            OSCAction::Send(data) => {
+                let mut args = Vec::with_capacity(data.args.len());
+                for arg in &data.args {
+                    args.push(match arg {
+                        OSCArg::Int(i) => OscType::Int(*i),
+                        OSCArg::Float(f) => OscType::Float(*f),
+                        OSCArg::String(s) => OscType::String(vars.render(s).await),
+                        OSCArg::Bool(b) => OscType::Bool(*b),
+                    });
+                }
+
+                conn.send(
+                    &data.target,
+                    OscMessage {
+                        addr: vars.render(&data.address).await,
+                        args,
+                    },
+                )
+                .await
+            }
+        }
+    }
+}
+
+// targets maps a name to the address messages are sent to (for example "127.0.0.1:9000")
+// If listen_addr is set (for example "0.0.0.0:8000") incoming messages are sent out as events, so
+// they can run actions using triggers.json
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OSCConfig {
+    pub targets: HashMap<String, String>,
+    pub listen_addr: Option<String>,
+}
+
+pub struct OSCConnector {
+    socket: Option<UdpSocket>,
+    config: OSCConfig,
+}
+
+impl OSCConnector {
+    pub async fn new(config: OSCConfig) -> OSCConnector {
+        OSCConnector {
+            socket: UdpSocket::bind("0.0.0.0:0").await.ok(),
+            config,
+        }
+    }
+
+    // Uses a separate socket bound to listen_addr, and retries every few seconds if binding it
+    // fails (address in use etc)
+    pub fn start_event_listener(&self, sender: broadcast::Sender<PluginEvent>) {
+        let Some(listen_addr) = self.config.listen_addr.clone() else {
+            return;
+        };
+
+        tokio::spawn(async move {
+            loop {
+                match UdpSocket::bind(&listen_addr).await {
+                    Ok(socket) => {
+                        println!("Listening for OSC messages on {listen_addr}");
+                        let mut buf = [0u8; rosc::decoder::MTU];
+                        loop {
+                            let len = match socket.recv(&mut buf).await {
+                                Ok(len) => len,
+                                Err(e) => {
+                                    println!("Unable to receive OSC message: {e}");
+                                    break;
+                                }
+                            };
+
+                            match rosc::decoder::decode_udp(&buf[..len]) {
+                                Ok((_, packet)) => Self::send_events(packet, &sender),
+                                Err(e) => println!("Received invalid OSC packet: {e}"),
+                            }
+                        }
+                    }
+                    Err(e) => println!("Unable to listen for OSC messages on {listen_addr}: {e}"),
+                }
+
+                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            }
+        });
+    }
+
+    // Bundles are flattened into their messages
+    fn send_events(packet: OscPacket, sender: &broadcast::Sender<PluginEvent>) {
+        match packet {
+            OscPacket::Message(msg) => {
+                PluginEvent::send(
+                    sender,
+                    PluginEventType::OSC(OSCEventType::MessageReceived),
+                    Some(msg.addr),
+                );
+            }
+            OscPacket::Bundle(bundle) => {
+                for packet in bundle.content {
+                    Self::send_events(packet, sender);
+                }
+            }
+        }
+    }
+
+    async fn check_conn(&mut self) -> bool {
+        if self.socket.is_none() {
+            self.socket = UdpSocket::bind("0.0.0.0:0").await.ok();
+        }
+
+        self.socket.is_some()
+    }
+
+    pub async fn send(&mut self, target: &str, msg: OscMessage) -> Result<(), String> {
+        let Some(addr) = self.config.targets.get(target).cloned() else {
+            return Err(format!("OSC target {target} not configured"));
+        };
+
+        let packet = rosc::encoder::encode(&OscPacket::Message(msg)).map_err(|e| e.to_string())?;
+
+        if !self.check_conn().await {
+            return Err("Unable to create OSC socket".to_string());
+        }
+
+        match self.socket.as_ref().unwrap().send_to(&packet, &addr).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                self.socket = None;
+                Err(e.to_string())
+            }
+        }
+    }
+}
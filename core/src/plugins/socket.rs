@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use futures_util::{FutureExt, SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::variables::Variables;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+// endpoint is the name of one of the endpoints in the socket config
+// The text can reference variables using {{name}}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SocketSendTextInput {
+    pub endpoint: String,
+    pub text: String,
+}
+
+// Strings anywhere in the JSON can reference variables using {{name}}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SocketSendJsonInput {
+    pub endpoint: String,
+    pub json: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "tag", content = "content")]
+pub enum SocketAction {
+    SendText(SocketSendTextInput),
+    SendJson(SocketSendJsonInput),
+}
+
+impl SocketAction {
+    pub async fn run(&self, conn: &mut SocketConnector, vars: &Variables) -> Result<(), String> {
+        match self {
+            SocketAction::SendText(data) => {
+                conn.send(&data.endpoint, vars.render(&data.text).await)
+                    .await
+            }
+            SocketAction::SendJson(data) => {
+                conn.send(
+                    &data.endpoint,
+                    vars.render_json(&data.json).await.to_string(),
+                )
+                .await
+            }
+        }
+    }
+}
+
+// WebSocket takes a URL (ws://localhost:3000), Tcp and Udp take an address (localhost:3000)
+// Messages sent over TCP aren't delimited, so line based protocols need to end the text with \n
+#[derive(Serialize, Deserialize, Clone)]
+pub enum SocketEndpoint {
+    WebSocket(String),
+    Tcp(String),
+    Udp(String),
+}
+
+type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+enum SocketConnection {
+    WebSocket(Box<WebSocket>),
+    Tcp(TcpStream),
+    Udp(UdpSocket),
+}
+
+impl SocketConnection {
+    async fn connect(endpoint: &SocketEndpoint) -> Result<SocketConnection, String> {
+        let conn = async {
+            match endpoint {
+                SocketEndpoint::WebSocket(url) => tokio_tungstenite::connect_async(url)
+                    .await
+                    .map(|(ws, _)| SocketConnection::WebSocket(Box::new(ws)))
+                    .map_err(|e| e.to_string()),
+                SocketEndpoint::Tcp(addr) => TcpStream::connect(addr)
+                    .await
+                    .map(SocketConnection::Tcp)
+                    .map_err(|e| e.to_string()),
+                SocketEndpoint::Udp(addr) => {
+                    let socket = UdpSocket::bind("0.0.0.0:0")
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    socket.connect(addr).await.map_err(|e| e.to_string())?;
+                    Ok(SocketConnection::Udp(socket))
+                }
+            }
+        };
+
+        match timeout(CONNECT_TIMEOUT, conn).await {
+            Ok(res) => res,
+            Err(_) => Err("Timed out while connecting".to_string()),
+        }
+    }
+
+    // Nothing reads from the connections otherwise, so anything received is discarded here first
+    // This is also what notices the other end closing the connection (and answers WebSocket pings)
+    fn discard_received(&mut self) -> Result<(), String> {
+        match self {
+            SocketConnection::WebSocket(ws) => loop {
+                match ws.next().now_or_never() {
+                    Some(Some(Ok(_))) => {}
+                    Some(Some(Err(e))) => return Err(e.to_string()),
+                    Some(None) => return Err("Connection closed".to_string()),
+                    None => return Ok(()),
+                }
+            },
+            SocketConnection::Tcp(stream) => {
+                let mut buf = [0u8; 1024];
+                loop {
+                    match stream.try_read(&mut buf) {
+                        Ok(0) => return Err("Connection closed".to_string()),
+                        Ok(_) => {}
+                        Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => return Ok(()),
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+            }
+            SocketConnection::Udp(_) => Ok(()),
+        }
+    }
+
+    // Can fail after part of the message was already sent, so it isn't safe to send it again
+    async fn send(&mut self, msg: &str) -> Result<(), String> {
+        match self {
+            SocketConnection::WebSocket(ws) => ws
+                .send(Message::Text(msg.to_string()))
+                .await
+                .map_err(|e| e.to_string()),
+            SocketConnection::Tcp(stream) => stream
+                .write_all(msg.as_bytes())
+                .await
+                .map_err(|e| e.to_string()),
+            SocketConnection::Udp(socket) => socket
+                .send(msg.as_bytes())
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SocketConfig {
+    pub endpoints: HashMap<String, SocketEndpoint>,
+}
+
+// Connections are only made when an endpoint is first used (so unreachable endpoints don't hold up
+// startup), and like the OBS connector one that breaks while sending is made again the next time
+pub struct SocketConnector {
+    connections: HashMap<String, Option<SocketConnection>>,
+    config: SocketConfig,
+}
+
+impl SocketConnector {
+    pub fn new(config: SocketConfig) -> SocketConnector {
+        SocketConnector {
+            connections: HashMap::new(),
+            config,
+        }
+    }
+
+    async fn check_conn(&mut self, name: &str) -> Result<&mut SocketConnection, String> {
+        let Some(endpoint) = self.config.endpoints.get(name) else {
+            return Err(format!("Socket endpoint {name} not configured"));
+        };
+
+        let conn = self.connections.entry(name.to_string()).or_default();
+        if conn.is_none() {
+            *conn = Some(
+                SocketConnection::connect(endpoint)
+                    .await
+                    .map_err(|e| format!("Unable to connect to {name}: {e}"))?,
+            );
+        }
+
+        Ok(conn.as_mut().unwrap())
+    }
+
+    // A connection the other end has closed is only noticed when it is used, in which case a new
+    // one is made before sending
+    // If sending itself fails nothing is retried (the message may have partly arrived already), the
+    // connection is just made again next time
+    pub async fn send(&mut self, name: &str, msg: String) -> Result<(), String> {
+        if self.check_conn(name).await?.discard_received().is_err() {
+            self.connections.insert(name.to_string(), None);
+        }
+
+        match self.check_conn(name).await?.send(&msg).await {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                self.connections.insert(name.to_string(), None);
+                Err(e)
+            }
+        }
+    }
+}
@@ -1,14 +1,55 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
 
+use derive_more::Display;
 use serde::{Deserialize, Serialize};
 use tokio::fs::{read_to_string, write};
+use tokio::sync::broadcast;
+use tokio::task::AbortHandle;
+use vtubestudio::client::ClientEvent;
+use vtubestudio::data::{
+    AngleRelativeTo, Event, EventSubscriptionRequest, HotkeyTriggeredEventConfig,
+    InjectParameterDataMode, ModelLoadedEventConfig, ModelMovedEventConfig, SizeRelativeTo,
+    TrackingStatusChangedEventConfig, VertexPinType,
+};
 use vtubestudio::Client;
 
+use crate::plugins::{PluginEvent, PluginEventType};
+use crate::variables::Variables;
+
 #[derive(Serialize, Deserialize, Clone)]
 pub enum VTSQuery {
     ActiveModelId,
+    // Whether the current model has any stored position
     StoredModelPositionExists,
+    // Whether the current model has a position stored under the given name
+    ModelPositionExists(String),
     Version,
+    // Base physics and wind strength of the current model (0 to 100)
+    PhysicsStrength,
+    PhysicsWind,
+    // Whether physics are turned on for the current model
+    PhysicsEnabled,
+    // Whether a plugin (including this one) is currently overriding the physics
+    PhysicsOverrideActive,
+    NdiActive,
+    // The scene lighting overlay can only be read, the VTS API has no way to change it (ColorTint
+    // with all set is the closest equivalent)
+    SceneLightingActive,
+    // The scene lighting overlay color as #rrggbb
+    SceneLightingColor,
+    // Takes the expression ID/file name
+    ExpressionActive(String),
+    FaceFound,
+    ActiveModelName,
+    // Number of items currently in the scene
+    ItemCount,
+    // Same ranges as in VTSMoveModelInput
+    ModelPositionX,
+    ModelPositionY,
+    ModelRotation,
+    ModelSize,
 }
 
 impl VTSQuery {
@@ -16,32 +57,203 @@ impl VTSQuery {
         match self {
             VTSQuery::ActiveModelId => conn.get_current_model_id().await,
             VTSQuery::StoredModelPositionExists => {
-                if conn.get_stored_model_position_exists() {
+                if conn.get_stored_model_position_exists(None).await? {
+                    Ok("true".to_string())
+                } else {
+                    Ok("false".to_string())
+                }
+            }
+            VTSQuery::ModelPositionExists(var_id) => {
+                if conn.get_stored_model_position_exists(Some(var_id)).await? {
                     Ok("true".to_string())
                 } else {
                     Ok("false".to_string())
                 }
             }
             VTSQuery::Version => conn.get_vts_version().await,
+            VTSQuery::PhysicsStrength => Ok(conn.get_physics().await?.base_strength.to_string()),
+            VTSQuery::PhysicsWind => Ok(conn.get_physics().await?.base_wind.to_string()),
+            VTSQuery::PhysicsEnabled => {
+                Ok(conn.get_physics().await?.physics_switched_on.to_string())
+            }
+            VTSQuery::PhysicsOverrideActive => Ok(conn
+                .get_physics()
+                .await?
+                .api_physics_override_active
+                .to_string()),
+            VTSQuery::NdiActive => Ok(conn.get_ndi_active().await?.to_string()),
+            VTSQuery::SceneLightingActive => {
+                Ok(conn.get_scene_lighting().await?.active.to_string())
+            }
+            VTSQuery::SceneLightingColor => {
+                let info = conn.get_scene_lighting().await?;
+                Ok(format!(
+                    "#{:02x}{:02x}{:02x}",
+                    info.color_overlay_r, info.color_overlay_g, info.color_overlay_b
+                ))
+            }
+            VTSQuery::ExpressionActive(expr) => {
+                Ok(conn.get_expression_active(expr).await?.to_string())
+            }
+            VTSQuery::FaceFound => Ok(conn.get_face_found().await?.to_string()),
+            VTSQuery::ActiveModelName => Ok(conn.get_current_model_info().await?.model_name),
+            VTSQuery::ItemCount => Ok(conn.get_item_count().await?.to_string()),
+            VTSQuery::ModelPositionX => Ok(conn.get_current_model_position().await?.0.to_string()),
+            VTSQuery::ModelPositionY => Ok(conn.get_current_model_position().await?.1.to_string()),
+            VTSQuery::ModelRotation => Ok(conn.get_current_model_position().await?.2.to_string()),
+            VTSQuery::ModelSize => Ok(conn.get_current_model_position().await?.3.to_string()),
         }
     }
 }
 
+// Fields left as None are not changed
+// If relative is set the values are added to the current ones instead, e.g. y: 0.1 moves the
+// model up a bit and size: 10 zooms in
 #[derive(Serialize, Deserialize, Clone)]
 pub struct VTSMoveModelInput {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub rotation: Option<f64>,
+    pub size: Option<f64>,
+    pub time_sec: f64,
+    #[serde(default)]
+    pub relative: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSModelPosition {
     pub x: f64,
     pub y: f64,
     pub rotation: f64,
     pub size: f64,
-    pub time_sec: f64,
 }
 
+// Stored positions by model ID and then by name, see load_position_store
+type VTSPositionStore = HashMap<String, HashMap<String, VTSModelPosition>>;
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct VTSRestoreModelPositionInput {
     pub var_id: String,
     pub time_sec: f64,
 }
 
+// id is the name of a default (FaceAngleX, MouthOpen, etc) or custom parameter
+// weight is between 0 and 1 (default 1) and controls how much the tracking data is overridden
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSParameterValue {
+    pub id: String,
+    pub value: f64,
+    pub weight: Option<f64>,
+}
+
+// VTS goes back to the tracked values about a second after the last injection, so if
+// duration_sec is set the values keep being sent in the background until it runs out
+// If add is true the values are added to the tracked values instead of replacing them
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSInjectParametersInput {
+    pub parameters: Vec<VTSParameterValue>,
+    pub add: bool,
+    pub duration_sec: Option<f64>,
+}
+
+// Custom parameters need to be created before values can be injected into them
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSCreateParameterInput {
+    pub name: String,
+    pub min: f64,
+    pub max: f64,
+    pub default_value: f64,
+}
+
+// file_name is the item file in the VTS items folder
+// x and y are between -1 and 1 like the model position, size is between 0 and 1 and rotation is
+// in degrees
+// order is the layer, if None (or the layer is taken) VTS picks a free one
+// If instance_var is set the instance ID of the loaded item is stored in it, so it can be
+// referenced by the other item actions using {{name}}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSLoadItemInput {
+    pub file_name: String,
+    pub x: f64,
+    pub y: f64,
+    pub size: f64,
+    pub rotation: f64,
+    pub fade_time: f64,
+    pub order: Option<i32>,
+    pub flipped: bool,
+    pub locked: bool,
+    pub instance_var: Option<String>,
+}
+
+// The instance IDs can reference runtime variables, all items matching either list are unloaded
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSUnloadItemsInput {
+    pub instance_ids: Vec<String>,
+    pub file_names: Vec<String>,
+}
+
+// Pins the item to the given art mesh of the current model, at its center or at a random point
+// angle (degrees) is relative to the model and size is absolute (0 to 1), None keeps the current
+// values
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSPinItemInput {
+    pub instance_id: String,
+    pub art_mesh_id: String,
+    pub random_position: bool,
+    pub angle: Option<f64>,
+    pub size: Option<f64>,
+}
+
+// Fields left as None are left unchanged, play starts or stops the animation
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSItemAnimationInput {
+    pub instance_id: String,
+    pub framerate: Option<f64>,
+    pub frame: Option<i32>,
+    pub brightness: Option<f64>,
+    pub opacity: Option<f64>,
+    pub play: Option<bool>,
+}
+
+// Art meshes matching any of the lists are tinted (or all of them if all is set), the *_contains
+// lists match on substrings, e.g. "blush"
+// mix is the weight against the scene lighting (0 to 1, VTS defaults to 1)
+// If reset_after_sec is set the matched art meshes are set back to white after that time
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSColorTintInput {
+    pub all: bool,
+    pub names: Vec<String>,
+    pub name_contains: Vec<String>,
+    pub tags: Vec<String>,
+    pub tag_contains: Vec<String>,
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+    pub mix: Option<f64>,
+    pub rainbow: bool,
+    pub reset_after_sec: Option<f64>,
+}
+
+// Multipliers for a single physics group (0 to 2, 1 is unchanged), None leaves it alone
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSPhysicsMultiplier {
+    pub group_id: String,
+    pub strength: Option<f64>,
+    pub wind: Option<f64>,
+}
+
+// strength and wind replace the base values of the current model (0 to 100)
+// VTS only keeps overrides for 0.5 to 5 seconds, so duration_sec has to be at least 0.5 and longer
+// overrides are resent in the background until duration_sec is over
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VTSPhysicsOverrideInput {
+    pub strength: Option<f64>,
+    pub wind: Option<f64>,
+    pub multipliers: Vec<VTSPhysicsMultiplier>,
+    pub duration_sec: f64,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(tag = "tag", content = "content")]
 pub enum VTSAction {
@@ -51,13 +263,34 @@ pub enum VTSAction {
     LoadModel(String),
     MoveModel(VTSMoveModelInput),
     TriggerHotkey(String),
+    // The ByName variants look up the ID when run, so they keep working after the model is
+    // re-imported (which changes the IDs)
+    ToggleExpressionByName(String),
+    EnableExpressionByName(String),
+    DisableExpressionByName(String),
+    LoadModelByName(String),
+    TriggerHotkeyByName(String),
     SaveCurrentModelPosition(String),
     RestoreModelPosition(VTSRestoreModelPositionInput),
+    DeleteModelPosition(String),
+    InjectParameters(VTSInjectParametersInput),
+    StopInjectingParameters(Vec<String>),
+    CreateParameter(VTSCreateParameterInput),
+    ColorTint(VTSColorTintInput),
+    OverridePhysics(VTSPhysicsOverrideInput),
+    StopOverridingPhysics,
+    SetNdiActive(bool),
+    ToggleNdi,
+    LoadItem(VTSLoadItemInput),
+    UnloadItems(VTSUnloadItemsInput),
+    PinItem(VTSPinItemInput),
+    UnpinItem(String),
+    ControlItemAnimation(VTSItemAnimationInput),
     CheckConnection,
 }
 
 impl VTSAction {
-    pub async fn run(&self, conn: &mut VTSConnector) -> Result<(), String> {
+    pub async fn run(&self, conn: &mut VTSConnector, vars: &Variables) -> Result<(), String> {
         match self {
             VTSAction::ToggleExpression(expr) => conn.toggle_expression(expr).await,
             VTSAction::EnableExpression(expr) => conn.change_expression_state(expr, true).await,
@@ -65,10 +298,71 @@ impl VTSAction {
             VTSAction::LoadModel(model) => conn.load_model(model).await,
             VTSAction::MoveModel(info) => conn.move_model(info).await,
             VTSAction::TriggerHotkey(hotkey) => conn.trigger_hotkey(hotkey).await,
+            VTSAction::ToggleExpressionByName(name) => {
+                conn.run_by_name(VTSIdAction::ToggleExpression, name).await
+            }
+            VTSAction::EnableExpressionByName(name) => {
+                conn.run_by_name(VTSIdAction::ChangeExpressionState(true), name)
+                    .await
+            }
+            VTSAction::DisableExpressionByName(name) => {
+                conn.run_by_name(VTSIdAction::ChangeExpressionState(false), name)
+                    .await
+            }
+            VTSAction::LoadModelByName(name) => {
+                conn.run_by_name(VTSIdAction::LoadModel, name).await
+            }
+            VTSAction::TriggerHotkeyByName(name) => {
+                conn.run_by_name(VTSIdAction::TriggerHotkey, name).await
+            }
             VTSAction::SaveCurrentModelPosition(var_id) => {
                 conn.save_current_model_position(var_id).await
             }
             VTSAction::RestoreModelPosition(data) => conn.restore_model_position(data).await,
+            VTSAction::DeleteModelPosition(var_id) => conn.delete_model_position(var_id).await,
+            VTSAction::InjectParameters(data) => conn.inject_parameters(data).await,
+            VTSAction::StopInjectingParameters(ids) => {
+                conn.stop_injecting_parameters(ids);
+                Ok(())
+            }
+            VTSAction::CreateParameter(data) => conn.create_parameter(data).await,
+            VTSAction::ColorTint(data) => conn.color_tint(data).await,
+            VTSAction::OverridePhysics(data) => conn.override_physics(data).await,
+            VTSAction::StopOverridingPhysics => {
+                conn.stop_overriding_physics();
+                Ok(())
+            }
+            VTSAction::SetNdiActive(active) => conn.set_ndi_active(*active).await,
+            VTSAction::ToggleNdi => {
+                let active = conn.get_ndi_active().await?;
+                conn.set_ndi_active(!active).await
+            }
+            VTSAction::LoadItem(data) => {
+                let instance_id = conn.load_item(data).await?;
+                if let Some(var) = &data.instance_var {
+                    vars.set(var, instance_id).await;
+                }
+                Ok(())
+            }
+            VTSAction::UnloadItems(data) => {
+                let mut instance_ids = Vec::with_capacity(data.instance_ids.len());
+                for id in &data.instance_ids {
+                    instance_ids.push(vars.render(id).await);
+                }
+                conn.unload_items(instance_ids, data.file_names.clone())
+                    .await
+            }
+            VTSAction::PinItem(data) => {
+                let instance_id = vars.render(&data.instance_id).await;
+                conn.pin_item(&instance_id, data).await
+            }
+            VTSAction::UnpinItem(instance_id) => {
+                conn.unpin_item(&vars.render(instance_id).await).await
+            }
+            VTSAction::ControlItemAnimation(data) => {
+                let instance_id = vars.render(&data.instance_id).await;
+                conn.control_item_animation(&instance_id, data).await
+            }
             VTSAction::CheckConnection => conn.get_vts_version().await.map(|_| ()),
         }
     }
@@ -80,9 +374,173 @@ pub struct VTSConfig {
     pub token_file: String,
 }
 
+// The subject of ModelLoaded, ModelUnloaded and ModelMoved is the model name and for
+// HotkeyTriggered it is the hotkey name, FaceFound and FaceLost have no subject
+// ModelMoved is sent continuously while the model is being dragged around
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum VTSEventType {
+    ModelLoaded,
+    ModelUnloaded,
+    HotkeyTriggered,
+    FaceFound,
+    FaceLost,
+    ModelMoved,
+}
+
+impl VTSEventType {
+    // Returns None for the events that are not forwarded
+    fn from_vts_event(event: Event) -> Option<PluginEvent> {
+        let (event_type, subject) = match event {
+            Event::ModelLoaded(e) => {
+                if e.model_loaded {
+                    (VTSEventType::ModelLoaded, Some(e.model_name))
+                } else {
+                    (VTSEventType::ModelUnloaded, Some(e.model_name))
+                }
+            }
+            Event::HotkeyTriggered(e) => (VTSEventType::HotkeyTriggered, Some(e.hotkey_name)),
+            Event::TrackingStatusChanged(e) => {
+                if e.face_found {
+                    (VTSEventType::FaceFound, None)
+                } else {
+                    (VTSEventType::FaceLost, None)
+                }
+            }
+            Event::ModelMoved(e) => (VTSEventType::ModelMoved, Some(e.model_name)),
+            _ => return None,
+        };
+
+        Some(PluginEvent {
+            event_type: PluginEventType::VTS(event_type),
+            subject,
+        })
+    }
+}
+
+// Actions that take an ID which can also be looked up by name, see run_by_name
+#[derive(Clone, Copy)]
+enum VTSIdAction {
+    ToggleExpression,
+    ChangeExpressionState(bool),
+    LoadModel,
+    TriggerHotkey,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Display)]
+enum VTSIdKind {
+    #[display("expression")]
+    Expression,
+    #[display("model")]
+    Model,
+    #[display("hotkey")]
+    Hotkey,
+}
+
+impl VTSIdAction {
+    fn kind(self) -> VTSIdKind {
+        match self {
+            VTSIdAction::ToggleExpression | VTSIdAction::ChangeExpressionState(_) => {
+                VTSIdKind::Expression
+            }
+            VTSIdAction::LoadModel => VTSIdKind::Model,
+            VTSIdAction::TriggerHotkey => VTSIdKind::Hotkey,
+        }
+    }
+}
+
+// IDs looked up by name, cleared whenever a model is loaded since expression and hotkey IDs
+// depend on the current model
+type VTSIdCache = Arc<Mutex<HashMap<(VTSIdKind, String), String>>>;
+
+// Fails if more than one ID matches the name, since picking one at random would be confusing
+fn unique_id(
+    mut ids: impl Iterator<Item = String>,
+    kind: VTSIdKind,
+    name: &str,
+) -> Result<String, String> {
+    let Some(id) = ids.next() else {
+        return Err(format!("No VTS {kind} named \"{name}\" found"));
+    };
+
+    match ids.count() {
+        0 => Ok(id),
+        n => Err(format!(
+            "{} VTS {kind}s are named \"{name}\", rename them in VTube Studio so the name is unique",
+            n + 1
+        )),
+    }
+}
+
+// Tasks are removed again when they end on their own, the number tells apart a task from a newer
+// one for the same parameter
+#[derive(Default)]
+struct VTSInjections {
+    tasks: Arc<Mutex<HashMap<String, (u64, AbortHandle)>>>,
+    next_task: u64,
+}
+
+impl VTSInjections {
+    // Sends req every 500ms until end, req has to contain a single parameter
+    fn spawn(
+        &mut self,
+        mut client: Client,
+        req: vtubestudio::data::InjectParameterDataRequest,
+        end: tokio::time::Instant,
+    ) {
+        let id = req.parameter_values[0].id.clone();
+        let task = self.next_task;
+        self.next_task += 1;
+
+        // Locked until the task is in the map, so it can't try to remove itself before that
+        let mut tasks = self.tasks.lock().unwrap();
+        let task_list = self.tasks.clone();
+        let param_id = id.clone();
+        let handle = tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+                if tokio::time::Instant::now() >= end {
+                    break;
+                }
+
+                if let Err(e) = client.send(&req).await {
+                    println!("Stopped injecting VTS parameter {param_id}: {e}");
+                    break;
+                }
+            }
+
+            let mut tasks = task_list.lock().unwrap();
+            if tasks.get(&param_id).is_some_and(|(t, _)| *t == task) {
+                tasks.remove(&param_id);
+            }
+        });
+
+        tasks.insert(id, (task, handle.abort_handle()));
+    }
+
+    fn stop(&self, ids: &[String]) {
+        let mut tasks = self.tasks.lock().unwrap();
+        for id in ids {
+            if let Some((_, handle)) = tasks.remove(id) {
+                handle.abort();
+            }
+        }
+    }
+}
+
 pub struct VTSConnector {
     client: Client,
-    position_store: HashMap<String, VTSMoveModelInput>,
+    id_cache: VTSIdCache,
+    position_store: VTSPositionStore,
+    // Where position_store is saved, positions are only kept in memory if this is None
+    position_file: Option<String>,
+    // Background tasks sending sustained parameter values, by parameter ID
+    injections: VTSInjections,
+    // Background task resending the physics override
+    physics_override: Option<AbortHandle>,
+    // Only set once start_event_listener is called, API events are dropped until then
+    event_sender: Arc<OnceLock<broadcast::Sender<PluginEvent>>>,
+    // VTS forgets the subscriptions whenever the connection drops
+    subscribed: Arc<AtomicBool>,
 }
 
 impl VTSConnector {
@@ -102,27 +560,134 @@ impl VTSConnector {
             .build_tungstenite();
 
         let token_file = conf.token_file.to_string();
+        let event_sender: Arc<OnceLock<broadcast::Sender<PluginEvent>>> = Arc::default();
+        let subscribed = Arc::new(AtomicBool::new(false));
+
+        let id_cache = VTSIdCache::default();
 
+        let task_sender = event_sender.clone();
+        let task_subscribed = subscribed.clone();
+        let task_id_cache = id_cache.clone();
         tokio::spawn(async move {
-            // This returns whenever the authentication middleware receives a new auth token.
-            // We can handle it by saving it somewhere, etc.
             while let Some(event) = events.next().await {
-                if let vtubestudio::client::ClientEvent::NewAuthToken(token) = event {
-                    println!("Got new auth token: {token}");
-                    match write(&token_file, token).await {
-                        Ok(_) => println!("Saved new token to {token_file}"),
-                        Err(e) => println!("Failed to save token: {e}"),
+                match event {
+                    // This is sent whenever the authentication middleware receives a new auth
+                    // token
+                    ClientEvent::NewAuthToken(token) => {
+                        println!("Got new auth token: {token}");
+                        match write(&token_file, token).await {
+                            Ok(_) => println!("Saved new token to {token_file}"),
+                            Err(e) => println!("Failed to save token: {e}"),
+                        }
+                    }
+                    ClientEvent::Disconnected => {
+                        task_subscribed.store(false, Ordering::Relaxed);
+                        // VTS may have been restarted with re-imported models
+                        task_id_cache.lock().unwrap().clear();
                     }
+                    ClientEvent::Api(event) => {
+                        if let Event::ModelLoaded(_) = event {
+                            task_id_cache.lock().unwrap().clear();
+                        }
+
+                        if let (Some(sender), Some(event)) =
+                            (task_sender.get(), VTSEventType::from_vts_event(event))
+                        {
+                            PluginEvent::send(sender, event.event_type, event.subject);
+                        }
+                    }
+                    _ => {}
                 }
             }
         });
 
         VTSConnector {
             client,
+            id_cache,
             position_store: HashMap::new(),
+            position_file: None,
+            injections: VTSInjections::default(),
+            physics_override: None,
+            event_sender,
+            subscribed,
         }
     }
 
+    // Only subscribes to the VTS events needed for event_types (the ones used by triggers), so for
+    // example ModelMoved isn't sent for every frame of a drag when nothing uses it
+    // The client connects lazily, so this keeps trying to subscribe every 5 seconds until it
+    // succeeds, and again after every disconnect
+    pub fn start_event_listener(
+        &self,
+        sender: broadcast::Sender<PluginEvent>,
+        event_types: &[VTSEventType],
+    ) {
+        if event_types.is_empty() {
+            return;
+        }
+
+        if self.event_sender.set(sender).is_err() {
+            // Already started
+            return;
+        }
+
+        let event_types = event_types.to_vec();
+        let mut client = self.client.clone();
+        let subscribed = self.subscribed.clone();
+
+        tokio::spawn(async move {
+            loop {
+                if !subscribed.load(Ordering::Relaxed) {
+                    match Self::subscribe_events(&mut client, &event_types).await {
+                        Ok(()) => {
+                            println!("Listening for VTS events");
+                            subscribed.store(true, Ordering::Relaxed);
+                        }
+                        Err(e) => println!("Unable to subscribe to VTS events: {e}"),
+                    }
+                }
+
+                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            }
+        });
+    }
+
+    async fn subscribe_events(
+        client: &mut Client,
+        event_types: &[VTSEventType],
+    ) -> Result<(), String> {
+        let wants = |types: &[VTSEventType]| types.iter().any(|t| event_types.contains(t));
+
+        let mut requests = Vec::new();
+        if wants(&[VTSEventType::ModelLoaded, VTSEventType::ModelUnloaded]) {
+            requests.push(EventSubscriptionRequest::subscribe(
+                &ModelLoadedEventConfig::default(),
+            ));
+        }
+        if wants(&[VTSEventType::HotkeyTriggered]) {
+            requests.push(EventSubscriptionRequest::subscribe(
+                &HotkeyTriggeredEventConfig::default(),
+            ));
+        }
+        if wants(&[VTSEventType::FaceFound, VTSEventType::FaceLost]) {
+            requests.push(EventSubscriptionRequest::subscribe(
+                &TrackingStatusChangedEventConfig::default(),
+            ));
+        }
+        if wants(&[VTSEventType::ModelMoved]) {
+            requests.push(EventSubscriptionRequest::subscribe(
+                &ModelMovedEventConfig::default(),
+            ));
+        }
+
+        for req in requests {
+            let req = req.map_err(|e| e.to_string())?;
+            client.send(&req).await.map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+
     pub async fn get_vts_version(&mut self) -> Result<String, String> {
         let resp = self
             .client
@@ -151,6 +716,8 @@ impl VTSConnector {
 
     // Takes the model ID as the parameter
     pub async fn load_model(&mut self, model: &str) -> Result<(), String> {
+        self.id_cache.lock().unwrap().clear();
+
         let resp = self
             .client
             .send(&vtubestudio::data::ModelLoadRequest {
@@ -175,11 +742,11 @@ impl VTSConnector {
             .client
             .send(&vtubestudio::data::MoveModelRequest {
                 time_in_seconds: info.time_sec,
-                values_are_relative_to_model: false,
-                position_x: Some(info.x),
-                position_y: Some(info.y),
-                rotation: Some(info.rotation),
-                size: Some(info.size),
+                values_are_relative_to_model: info.relative,
+                position_x: info.x,
+                position_y: info.y,
+                rotation: info.rotation,
+                size: info.size,
             })
             .await;
         match resp {
@@ -188,42 +755,147 @@ impl VTSConnector {
         }
     }
 
-    // Takes the current model position and stores it under var_id
-    async fn save_current_model_position(&mut self, var_id: &str) -> Result<(), String> {
-        let (x, y, rotation, size) = self.get_current_model_position().await?;
+    // Stores model positions in path from now on
+    // The server and configurator share the file, so it is read again before every use instead of
+    // trusting what was loaded at startup
+    pub async fn load_position_store(&mut self, path: String) {
+        self.position_file = Some(path);
+        if let Err(e) = self.reload_position_store().await {
+            println!("{e}");
+        }
+    }
 
-        let pos_struct = VTSMoveModelInput {
-            x,
-            y,
-            rotation,
-            size,
-            time_sec: 0.0,
+    async fn reload_position_store(&mut self) -> Result<(), String> {
+        let Some(path) = &self.position_file else {
+            return Ok(());
         };
 
-        self.position_store.insert(var_id.to_string(), pos_struct);
+        self.position_store = match read_to_string(path).await {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| format!("Unable to parse stored VTS model positions: {e}"))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(format!("Unable to read stored VTS model positions: {e}")),
+        };
 
         Ok(())
     }
 
+    async fn save_position_store(&mut self) -> Result<(), String> {
+        let Some(path) = self.position_file.clone() else {
+            return Ok(());
+        };
+
+        let contents =
+            serde_json::to_string_pretty(&self.position_store).map_err(|e| e.to_string())?;
+        write(&path, contents)
+            .await
+            .map_err(|e| format!("Unable to save VTS model positions: {e}"))
+    }
+
+    // Takes the current model position and stores it under var_id for the current model
+    pub async fn save_current_model_position(&mut self, var_id: &str) -> Result<(), String> {
+        let info = self.get_current_model_info().await?;
+        let pos = info.model_position;
+        self.reload_position_store().await?;
+
+        self.position_store
+            .entry(info.model_id)
+            .or_default()
+            .insert(
+                var_id.to_string(),
+                VTSModelPosition {
+                    x: pos.position_x,
+                    y: pos.position_y,
+                    rotation: pos.rotation,
+                    size: pos.size,
+                },
+            );
+
+        self.save_position_store().await
+    }
+
     // Takes the model position stored under var_id and moves the model to said position
+    // The position is kept, so it can be restored again later
     // time_sec is the same as in the move model action
     async fn restore_model_position(
         &mut self,
         data: &VTSRestoreModelPositionInput,
     ) -> Result<(), String> {
-        if let Some(mut pos_struct) = self.position_store.remove(&data.var_id) {
-            pos_struct.time_sec = data.time_sec;
-            return self.move_model(&pos_struct).await;
+        let model_id = self.get_current_model_id().await?;
+        self.reload_position_store().await?;
+
+        let Some(pos) = self
+            .position_store
+            .get(&model_id)
+            .and_then(|positions| positions.get(&data.var_id))
+            .cloned()
+        else {
+            return Err(format!(
+                "Variable {} does not have a stored position for the current model",
+                data.var_id
+            ));
+        };
+
+        self.move_model(&VTSMoveModelInput {
+            x: Some(pos.x),
+            y: Some(pos.y),
+            rotation: Some(pos.rotation),
+            size: Some(pos.size),
+            time_sec: data.time_sec,
+            relative: false,
+        })
+        .await
+    }
+
+    pub async fn delete_model_position(&mut self, var_id: &str) -> Result<(), String> {
+        let model_id = self.get_current_model_id().await?;
+        self.reload_position_store().await?;
+
+        let removed = self
+            .position_store
+            .get_mut(&model_id)
+            .and_then(|positions| positions.remove(var_id));
+
+        if removed.is_none() {
+            return Err(format!(
+                "Variable {var_id} does not have a stored position for the current model"
+            ));
         }
 
-        Err(format!(
-            "Variable {} does not have a stored position",
-            data.var_id
-        ))
+        self.save_position_store().await
     }
 
-    fn get_stored_model_position_exists(&self) -> bool {
-        !self.position_store.is_empty()
+    // Checks the current model for a position stored under var_id, or any position if None
+    async fn get_stored_model_position_exists(
+        &mut self,
+        var_id: Option<&String>,
+    ) -> Result<bool, String> {
+        let model_id = self.get_current_model_id().await?;
+        self.reload_position_store().await?;
+
+        let Some(positions) = self.position_store.get(&model_id) else {
+            return Ok(false);
+        };
+
+        Ok(match var_id {
+            Some(var_id) => positions.contains_key(var_id),
+            None => !positions.is_empty(),
+        })
+    }
+
+    // The names of the positions stored for the current model
+    pub async fn get_model_position_name_list(&mut self) -> Result<Vec<String>, String> {
+        let model_id = self.get_current_model_id().await?;
+        self.reload_position_store().await?;
+
+        let mut out: Vec<String> = self
+            .position_store
+            .get(&model_id)
+            .map(|positions| positions.keys().cloned().collect())
+            .unwrap_or_default();
+        out.sort();
+
+        Ok(out)
     }
 
     // Takes the expression ID/file name and whether to enable or disable the expression
@@ -268,6 +940,23 @@ impl VTSConnector {
             .await
     }
 
+    // Takes the expression ID/file name
+    pub async fn get_expression_active(&mut self, expr: &str) -> Result<bool, String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::ExpressionStateRequest {
+                details: false,
+                expression_file: Some(expr.to_string()),
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+
+        match resp.expressions.first() {
+            Some(e) => Ok(e.active),
+            None => Err("Expression not found in current model".to_string()),
+        }
+    }
+
     async fn get_expression_list(&mut self) -> Result<Vec<vtubestudio::data::Expression>, String> {
         let current_state = self
             .client
@@ -295,15 +984,14 @@ impl VTSConnector {
     }
 
     pub async fn get_expression_id_from_name(&mut self, name: &str) -> Result<String, String> {
-        let exprs = self.get_expression_list().await?;
-
-        for expr in exprs {
-            if name == expr.name {
-                return Ok(expr.file);
-            }
-        }
-
-        Err("Expression with given name not found for the current model".to_string())
+        let ids = self
+            .get_expression_list()
+            .await?
+            .into_iter()
+            .filter(|e| e.name == name)
+            .map(|e| e.file);
+
+        unique_id(ids, VTSIdKind::Expression, name)
     }
 
     pub async fn get_expression_name_from_id(&mut self, id: &str) -> Result<String, String> {
@@ -342,15 +1030,14 @@ impl VTSConnector {
     }
 
     pub async fn get_model_id_from_name(&mut self, name: &str) -> Result<String, String> {
-        let models = self.get_model_list().await?;
-
-        for model in models {
-            if name == model.model_name {
-                return Ok(model.model_id);
-            }
-        }
-
-        Err("No model found with the given name".to_string())
+        let ids = self
+            .get_model_list()
+            .await?
+            .into_iter()
+            .filter(|m| m.model_name == name)
+            .map(|m| m.model_id);
+
+        unique_id(ids, VTSIdKind::Model, name)
     }
 
     pub async fn get_model_name_from_id(&mut self, id: &str) -> Result<String, String> {
@@ -383,13 +1070,14 @@ impl VTSConnector {
     }
 
     pub async fn get_hotkey_id_from_name(&mut self, name: &str) -> Result<String, String> {
-        for hotkey in self.get_hotkey_list(None).await? {
-            if name == hotkey.name {
-                return Ok(hotkey.hotkey_id);
-            }
-        }
-
-        Err("Hotkey with given name not found in current model".to_string())
+        let ids = self
+            .get_hotkey_list(None)
+            .await?
+            .into_iter()
+            .filter(|h| h.name == name)
+            .map(|h| h.hotkey_id);
+
+        unique_id(ids, VTSIdKind::Hotkey, name)
     }
 
     pub async fn get_hotkey_name_from_id(&mut self, id: &str) -> Result<String, String> {
@@ -437,4 +1125,500 @@ impl VTSConnector {
             Err(e) => Err(e.to_string()),
         }
     }
+
+    // Looks up the ID for the name and runs the action with it
+    // If a cached ID fails it might be out of date (a model loaded without us noticing), so it is
+    // looked up again and retried once
+    async fn run_by_name(&mut self, action: VTSIdAction, name: &str) -> Result<(), String> {
+        let key = (action.kind(), name.to_string());
+        let cached = self.id_cache.lock().unwrap().get(&key).cloned();
+
+        if let Some(id) = cached {
+            if self.run_with_id(action, &id).await.is_ok() {
+                return Ok(());
+            }
+            self.id_cache.lock().unwrap().remove(&key);
+        }
+
+        let id = self.get_id_from_name(action.kind(), name).await?;
+        self.id_cache.lock().unwrap().insert(key, id.clone());
+
+        self.run_with_id(action, &id).await
+    }
+
+    async fn run_with_id(&mut self, action: VTSIdAction, id: &str) -> Result<(), String> {
+        match action {
+            VTSIdAction::ToggleExpression => self.toggle_expression(id).await,
+            VTSIdAction::ChangeExpressionState(active) => {
+                self.change_expression_state(id, active).await
+            }
+            VTSIdAction::LoadModel => self.load_model(id).await,
+            VTSIdAction::TriggerHotkey => self.trigger_hotkey(id).await,
+        }
+    }
+
+    async fn get_id_from_name(&mut self, kind: VTSIdKind, name: &str) -> Result<String, String> {
+        match kind {
+            VTSIdKind::Expression => self.get_expression_id_from_name(name).await,
+            VTSIdKind::Model => self.get_model_id_from_name(name).await,
+            VTSIdKind::Hotkey => self.get_hotkey_id_from_name(name).await,
+        }
+    }
+
+    pub async fn inject_parameters(
+        &mut self,
+        data: &VTSInjectParametersInput,
+    ) -> Result<(), String> {
+        let req = vtubestudio::data::InjectParameterDataRequest {
+            parameter_values: data
+                .parameters
+                .iter()
+                .map(|p| vtubestudio::data::ParameterValue {
+                    id: p.id.clone(),
+                    value: p.value,
+                    weight: p.weight,
+                })
+                .collect(),
+            face_found: false,
+            mode: Some(if data.add {
+                InjectParameterDataMode::Add.into()
+            } else {
+                InjectParameterDataMode::Set.into()
+            }),
+        };
+
+        // Anything still sustaining these parameters would fight with the new values
+        let ids: Vec<String> = data.parameters.iter().map(|p| p.id.clone()).collect();
+        self.stop_injecting_parameters(&ids);
+
+        self.client.send(&req).await.map_err(|e| e.to_string())?;
+
+        let Some(duration_sec) = data.duration_sec else {
+            return Ok(());
+        };
+
+        let duration =
+            std::time::Duration::try_from_secs_f64(duration_sec).map_err(|e| e.to_string())?;
+        let end = tokio::time::Instant::now() + duration;
+
+        // One task per parameter, so stopping some of them keeps the others going
+        for value in req.parameter_values {
+            let req = vtubestudio::data::InjectParameterDataRequest {
+                parameter_values: vec![value],
+                face_found: req.face_found,
+                mode: req.mode.clone(),
+            };
+            self.injections.spawn(self.client.clone(), req, end);
+        }
+
+        Ok(())
+    }
+
+    // Stops the background tasks sending sustained values for the given parameter IDs
+    pub fn stop_injecting_parameters(&mut self, ids: &[String]) {
+        self.injections.stop(ids);
+    }
+
+    pub async fn create_parameter(&mut self, data: &VTSCreateParameterInput) -> Result<(), String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::ParameterCreationRequest {
+                parameter_name: data.name.clone(),
+                explanation: None,
+                min: data.min,
+                max: data.max,
+                default_value: data.default_value,
+            })
+            .await;
+        match resp {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub async fn color_tint(&mut self, data: &VTSColorTintInput) -> Result<(), String> {
+        let art_mesh_matcher = vtubestudio::data::ArtMeshMatcher {
+            tint_all: data.all,
+            art_mesh_number: Vec::new(),
+            name_exact: data.names.clone(),
+            name_contains: data.name_contains.clone(),
+            tag_exact: data.tags.clone(),
+            tag_contains: data.tag_contains.clone(),
+        };
+
+        self.client
+            .send(&vtubestudio::data::ColorTintRequest {
+                color_tint: vtubestudio::data::ColorTint {
+                    color_r: data.r,
+                    color_g: data.g,
+                    color_b: data.b,
+                    color_a: data.a,
+                    mix_with_scene_lighting_color: data.mix,
+                    jeb_: data.rainbow,
+                },
+                art_mesh_matcher: art_mesh_matcher.clone(),
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let Some(reset_after_sec) = data.reset_after_sec else {
+            return Ok(());
+        };
+
+        let delay =
+            std::time::Duration::try_from_secs_f64(reset_after_sec).map_err(|e| e.to_string())?;
+        let mut client = self.client.clone();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+
+            let resp = client
+                .send(&vtubestudio::data::ColorTintRequest {
+                    color_tint: vtubestudio::data::ColorTint {
+                        color_r: 255,
+                        color_g: 255,
+                        color_b: 255,
+                        color_a: 255,
+                        ..Default::default()
+                    },
+                    art_mesh_matcher,
+                })
+                .await;
+            if let Err(e) = resp {
+                println!("Failed to reset VTS color tint: {e}");
+            }
+        });
+
+        Ok(())
+    }
+
+    pub async fn override_physics(&mut self, data: &VTSPhysicsOverrideInput) -> Result<(), String> {
+        // The longest VTS keeps an override for, anything longer is resent before it runs out
+        const MAX_OVERRIDE_SECONDS: f64 = 5.0;
+        // VTS rejects anything shorter
+        const MIN_OVERRIDE_SECONDS: f64 = 0.5;
+
+        if data.duration_sec.is_nan() || data.duration_sec < MIN_OVERRIDE_SECONDS {
+            return Err(format!(
+                "Physics override duration has to be at least {MIN_OVERRIDE_SECONDS} seconds"
+            ));
+        }
+
+        let duration =
+            std::time::Duration::try_from_secs_f64(data.duration_sec).map_err(|e| e.to_string())?;
+
+        let mut req = vtubestudio::data::SetCurrentModelPhysicsRequest {
+            strength_overrides: Vec::new(),
+            wind_overrides: Vec::new(),
+        };
+
+        let base = |value: f64| vtubestudio::data::PhysicsOverride {
+            id: String::new(),
+            value,
+            set_base_value: true,
+            override_seconds: 0.0,
+        };
+        req.strength_overrides.extend(data.strength.map(base));
+        req.wind_overrides.extend(data.wind.map(base));
+
+        for group in &data.multipliers {
+            let multiplier = |value: f64| vtubestudio::data::PhysicsOverride {
+                id: group.group_id.clone(),
+                value,
+                set_base_value: false,
+                override_seconds: 0.0,
+            };
+            req.strength_overrides
+                .extend(group.strength.map(multiplier));
+            req.wind_overrides.extend(group.wind.map(multiplier));
+        }
+
+        let set_override_seconds = |req: &mut vtubestudio::data::SetCurrentModelPhysicsRequest,
+                                    remaining: f64| {
+            // The last resend can run slightly past the end rather than be rejected
+            let seconds = remaining.clamp(MIN_OVERRIDE_SECONDS, MAX_OVERRIDE_SECONDS);
+            for o in req
+                .strength_overrides
+                .iter_mut()
+                .chain(req.wind_overrides.iter_mut())
+            {
+                o.override_seconds = seconds;
+            }
+        };
+
+        self.stop_overriding_physics();
+        set_override_seconds(&mut req, data.duration_sec);
+        self.client.send(&req).await.map_err(|e| e.to_string())?;
+
+        if data.duration_sec <= MAX_OVERRIDE_SECONDS {
+            return Ok(());
+        }
+
+        let mut client = self.client.clone();
+        let handle = tokio::spawn(async move {
+            let end = tokio::time::Instant::now() + duration;
+            loop {
+                // Resend a second before the previous override runs out
+                tokio::time::sleep(std::time::Duration::from_secs_f64(
+                    MAX_OVERRIDE_SECONDS - 1.0,
+                ))
+                .await;
+
+                let remaining = end.saturating_duration_since(tokio::time::Instant::now());
+                if remaining.is_zero() {
+                    return;
+                }
+
+                set_override_seconds(&mut req, remaining.as_secs_f64());
+                if let Err(e) = client.send(&req).await {
+                    println!("Stopped overriding VTS physics: {e}");
+                    return;
+                }
+            }
+        });
+        self.physics_override = Some(handle.abort_handle());
+
+        Ok(())
+    }
+
+    // The last override sent stays active for up to 5 seconds after this
+    pub fn stop_overriding_physics(&mut self) {
+        if let Some(handle) = self.physics_override.take() {
+            handle.abort();
+        }
+    }
+
+    async fn get_physics(
+        &mut self,
+    ) -> Result<vtubestudio::data::GetCurrentModelPhysicsResponse, String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::GetCurrentModelPhysicsRequest {})
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !resp.model_loaded {
+            return Err("No model is currently loaded".to_string());
+        }
+
+        Ok(resp)
+    }
+
+    pub async fn get_face_found(&mut self) -> Result<bool, String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::FaceFoundRequest {})
+            .await;
+        match resp {
+            Ok(r) => Ok(r.found),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub async fn get_ndi_active(&mut self) -> Result<bool, String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::NdiConfigRequest {
+                set_new_config: false,
+                ..Default::default()
+            })
+            .await;
+        match resp {
+            Ok(r) => Ok(r.ndi_active),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub async fn set_ndi_active(&mut self, active: bool) -> Result<(), String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::NdiConfigRequest {
+                set_new_config: true,
+                ndi_active: Some(active),
+                ..Default::default()
+            })
+            .await;
+        match resp {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    async fn get_scene_lighting(
+        &mut self,
+    ) -> Result<vtubestudio::data::SceneColorOverlayInfoResponse, String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::SceneColorOverlayInfoRequest {})
+            .await;
+        match resp {
+            Ok(r) => Ok(r),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    // Returns the art mesh names and tags of the current model
+    pub async fn get_art_mesh_list(&mut self) -> Result<(Vec<String>, Vec<String>), String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::ArtMeshListRequest {})
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !resp.model_loaded {
+            return Err("No model is currently loaded".to_string());
+        }
+
+        Ok((resp.art_mesh_names, resp.art_mesh_tags))
+    }
+
+    // Returns the instance ID of the new item
+    pub async fn load_item(&mut self, data: &VTSLoadItemInput) -> Result<String, String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::ItemLoadRequest {
+                file_name: data.file_name.clone(),
+                position_x: data.x,
+                position_y: data.y,
+                size: data.size,
+                rotation: data.rotation,
+                fade_time: data.fade_time,
+                order: data.order.unwrap_or_default(),
+                fail_if_order_taken: false,
+                smoothing: 0.0,
+                censored: false,
+                flipped: data.flipped,
+                locked: data.locked,
+                unload_when_plugin_disconnects: false,
+                custom_data_base64: None,
+                custom_data_ask_user_first: true,
+                custom_data_skip_asking_user_if_whitelisted: true,
+                custom_data_ask_timer: -1.0,
+            })
+            .await;
+        match resp {
+            Ok(r) => Ok(r.instance_id),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub async fn unload_items(
+        &mut self,
+        instance_ids: Vec<String>,
+        file_names: Vec<String>,
+    ) -> Result<(), String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::ItemUnloadRequest {
+                unload_all_in_scene: false,
+                unload_all_loaded_by_this_plugin: false,
+                allow_unloading_items_loaded_by_user_or_other_plugins: true,
+                instance_ids,
+                file_names,
+            })
+            .await;
+        match resp {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub async fn pin_item(
+        &mut self,
+        instance_id: &str,
+        data: &VTSPinItemInput,
+    ) -> Result<(), String> {
+        let model_id = self.get_current_model_id().await?;
+
+        let resp = self
+            .client
+            .send(&vtubestudio::data::ItemPinRequest {
+                pin: true,
+                item_instance_id: instance_id.to_string(),
+                angle_relative_to: if data.angle.is_some() {
+                    AngleRelativeTo::RelativeToModel.into()
+                } else {
+                    AngleRelativeTo::RelativeToCurrentItemRotation.into()
+                },
+                size_relative_to: if data.size.is_some() {
+                    SizeRelativeTo::RelativeToWorld.into()
+                } else {
+                    SizeRelativeTo::RelativeToCurrentItemSize.into()
+                },
+                vertex_pin_type: if data.random_position {
+                    VertexPinType::Random.into()
+                } else {
+                    VertexPinType::Center.into()
+                },
+                pin_info: vtubestudio::data::ArtMeshPosition {
+                    model_id,
+                    art_mesh_id: data.art_mesh_id.clone(),
+                    angle: data.angle.unwrap_or_default(),
+                    size: data.size.unwrap_or_default(),
+                    ..Default::default()
+                },
+            })
+            .await;
+        match resp {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub async fn unpin_item(&mut self, instance_id: &str) -> Result<(), String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::ItemPinRequest {
+                pin: false,
+                item_instance_id: instance_id.to_string(),
+                ..Default::default()
+            })
+            .await;
+        match resp {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub async fn control_item_animation(
+        &mut self,
+        instance_id: &str,
+        data: &VTSItemAnimationInput,
+    ) -> Result<(), String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::ItemAnimationControlRequest {
+                item_instance_id: instance_id.to_string(),
+                framerate: data.framerate,
+                frame: data.frame,
+                brightness: data.brightness,
+                opacity: data.opacity,
+                set_auto_stop_frames: false,
+                auto_stop_frames: Vec::new(),
+                set_animation_play_state: data.play.is_some(),
+                animation_play_state: data.play.unwrap_or_default(),
+            })
+            .await;
+        match resp {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub async fn get_item_count(&mut self) -> Result<i32, String> {
+        let resp = self
+            .client
+            .send(&vtubestudio::data::ItemListRequest {
+                include_available_spots: false,
+                include_item_instances_in_scene: false,
+                include_available_item_files: false,
+                only_items_with_file_name: None,
+                only_items_with_instance_id: None,
+            })
+            .await;
+        match resp {
+            Ok(r) => Ok(r.items_in_scene_count),
+            Err(e) => Err(e.to_string()),
+        }
+    }
 }
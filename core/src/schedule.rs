@@ -0,0 +1,227 @@
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use croner::Cron;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+use crate::action::ActionConfig;
+use crate::plugins::{PluginInstance, PluginStates, PluginType};
+
+// See examples/schedulegen.rs on how to generate the schedules.json file
+#[derive(Serialize, Deserialize)]
+pub struct ScheduleConfig {
+    pub schedules: Vec<Schedule>,
+}
+
+// Cron uses the usual 5 fields (minute hour day month weekday) in local time, with an optional
+// 6th field in front for seconds
+// At is a local time formatted as YYYY-MM-DD HH:MM:SS and only runs once, it is skipped if it's
+// already in the past when the server starts
+// Every runs first after the interval has passed, not immediately
+#[derive(Serialize, Deserialize, Clone)]
+pub enum ScheduleTime {
+    Cron(String),
+    At(String),
+    Every(f64),
+}
+
+// name is what the EnableSchedule/DisableSchedule actions use
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Schedule {
+    pub name: String,
+    pub time: ScheduleTime,
+    pub action_id: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl ScheduleConfig {
+    #[must_use]
+    pub fn from_file(path: &str) -> ScheduleConfig {
+        serde_json::from_str(&read_to_string(path).unwrap_or_else(|e| {
+            println!("{e}");
+            String::new()
+        }))
+        .unwrap_or_else(|e| {
+            println!("Unable to parse schedule config: {e}");
+            println!("Using defaults");
+            ScheduleConfig {
+                schedules: Vec::new(),
+            }
+        })
+    }
+
+    // Runs the actions of the schedules when they're due, for as long as the server runs
+    // Schedules are only enabled/disabled at runtime, restarting goes back to the enabled value
+    // in the config
+    pub async fn run(self, actions: Arc<ActionConfig>, states: Arc<PluginStates>) {
+        let switches = match states.plugins.get(&PluginType::General) {
+            Some(PluginInstance::General(general)) => general.schedules.clone(),
+            _ => ScheduleSwitches::default(),
+        };
+
+        for schedule in &self.schedules {
+            switches.register(&schedule.name, schedule.enabled);
+        }
+
+        futures_util::future::join_all(
+            self.schedules
+                .into_iter()
+                .map(|s| s.run(actions.clone(), states.clone(), switches.clone())),
+        )
+        .await;
+    }
+}
+
+impl Schedule {
+    async fn run(
+        self,
+        actions: Arc<ActionConfig>,
+        states: Arc<PluginStates>,
+        switches: ScheduleSwitches,
+    ) {
+        match &self.time {
+            ScheduleTime::Cron(expr) => {
+                let cron = match Cron::new(expr).with_seconds_optional().parse() {
+                    Ok(cron) => cron,
+                    Err(e) => {
+                        println!("Invalid cron expression for schedule {}: {e}", self.name);
+                        return;
+                    }
+                };
+
+                // Searching from the previous occurrence means a wall clock that is slightly behind
+                // after waking up can't find the one that just ran again, and from now if that is
+                // later so occurrences missed while the computer was asleep are skipped
+                let mut last: Option<DateTime<Local>> = None;
+                loop {
+                    let from = last.map_or_else(Local::now, |last| last.max(Local::now()));
+                    let next = match cron.find_next_occurrence(&from, false) {
+                        Ok(next) => next,
+                        Err(e) => {
+                            println!("Schedule {} will not run again: {e}", self.name);
+                            return;
+                        }
+                    };
+
+                    sleep_until(next).await;
+                    self.run_action(&actions, &states, &switches);
+                    last = Some(next);
+                }
+            }
+            ScheduleTime::At(timestamp) => {
+                let time = match NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M:%S")
+                    .ok()
+                    .and_then(|t| Local.from_local_datetime(&t).earliest())
+                {
+                    Some(time) => time,
+                    None => {
+                        println!("Invalid time for schedule {}: {timestamp}", self.name);
+                        return;
+                    }
+                };
+
+                if time < Local::now() {
+                    println!("Schedule {} is in the past, skipping it", self.name);
+                    return;
+                }
+
+                sleep_until(time).await;
+                self.run_action(&actions, &states, &switches);
+            }
+            ScheduleTime::Every(secs) => {
+                let period = match Duration::try_from_secs_f64(*secs) {
+                    Ok(period) if !period.is_zero() => period,
+                    _ => {
+                        println!("Invalid interval for schedule {}: {secs}", self.name);
+                        return;
+                    }
+                };
+
+                let mut interval =
+                    tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+                loop {
+                    interval.tick().await;
+                    self.run_action(&actions, &states, &switches);
+                }
+            }
+        }
+    }
+
+    fn run_action(
+        &self,
+        actions: &ActionConfig,
+        states: &Arc<PluginStates>,
+        switches: &ScheduleSwitches,
+    ) {
+        if !switches.is_enabled(&self.name) {
+            return;
+        }
+
+        actions.spawn(&self.action_id, states, "Scheduled");
+    }
+}
+
+async fn sleep_until(time: DateTime<Local>) {
+    if let Ok(wait) = (time - Local::now()).to_std() {
+        tokio::time::sleep(wait).await;
+    }
+}
+
+// Whether each schedule is enabled, shared with the General plugin for the enable/disable actions
+// Cloning gives another handle to the same values
+#[derive(Clone, Default)]
+pub struct ScheduleSwitches(Arc<std::sync::Mutex<HashMap<String, bool>>>);
+
+impl ScheduleSwitches {
+    fn register(&self, name: &str, enabled: bool) {
+        self.0.lock().unwrap().insert(name.to_string(), enabled);
+    }
+
+    fn is_enabled(&self, name: &str) -> bool {
+        self.0
+            .lock()
+            .unwrap()
+            .get(name)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, name: &str) -> Result<bool, String> {
+        self.0
+            .lock()
+            .unwrap()
+            .get(name)
+            .copied()
+            .ok_or_else(|| format!("Schedule {name} does not exist"))
+    }
+
+    pub fn set(&self, name: &str, enabled: bool) -> Result<(), String> {
+        match self.0.lock().unwrap().get_mut(name) {
+            Some(value) => {
+                *value = enabled;
+                Ok(())
+            }
+            None => Err(format!("Schedule {name} does not exist")),
+        }
+    }
+
+    pub fn toggle(&self, name: &str) -> Result<(), String> {
+        match self.0.lock().unwrap().get_mut(name) {
+            Some(value) => {
+                *value = !*value;
+                Ok(())
+            }
+            None => Err(format!("Schedule {name} does not exist")),
+        }
+    }
+}
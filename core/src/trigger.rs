@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use std::fs::read_to_string;
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::action::ActionConfig;
+use crate::plugins::{PluginEvent, PluginEventType, PluginStates};
+
+// See examples/triggergen.rs on how to generate the triggers.json file
+#[derive(Serialize, Deserialize)]
+pub struct TriggerConfig {
+    pub triggers: Vec<Trigger>,
+}
+
+impl TriggerConfig {
+    #[must_use]
+    pub fn from_file(path: &str) -> TriggerConfig {
+        serde_json::from_str(&read_to_string(path).unwrap_or_else(|e| {
+            println!("{e}");
+            String::new()
+        }))
+        .unwrap_or_else(|e| {
+            println!("Unable to parse trigger config: {e}");
+            println!("Using defaults");
+            TriggerConfig {
+                triggers: Vec::new(),
+            }
+        })
+    }
+
+    // The event types the triggers are for, what start_event_listeners needs
+    #[must_use]
+    pub fn get_event_types(&self) -> Vec<PluginEventType> {
+        let mut out: Vec<PluginEventType> = Vec::new();
+        for trigger in &self.triggers {
+            if !out.contains(&trigger.event) {
+                out.push(trigger.event.clone());
+            }
+        }
+
+        out
+    }
+
+    // Runs the action of every trigger matching each event received, until the event channel
+    // closes
+    // The plugin event listeners need to be started separately
+    pub async fn run(self, actions: Arc<ActionConfig>, states: Arc<PluginStates>) {
+        let mut events = states.events.subscribe();
+
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(count)) => {
+                    println!("Too many events at once, skipped {count} of them");
+                    continue;
+                }
+                Err(RecvError::Closed) => return,
+            };
+
+            for trigger in self.triggers.iter().filter(|t| t.matches(&event)) {
+                if let Some(var) = &trigger.subject_var {
+                    let subject = event.subject.clone().unwrap_or_default();
+                    states.variables.set(var, subject).await;
+                }
+
+                // Triggers can be used just to keep a variable up to date
+                if trigger.action_id.is_empty() {
+                    continue;
+                }
+
+                actions.spawn(&trigger.action_id, &states, "Triggered");
+            }
+        }
+    }
+}
+
+// filter is compared against the event subject (for example the scene name), None matches any
+// subject, including events that have no subject
+// If subject_var is set the event subject is stored in that variable before the action runs,
+// action_id can be left empty to only update the variable
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Trigger {
+    pub event: PluginEventType,
+    pub filter: Option<String>,
+    pub action_id: String,
+    #[serde(default)]
+    pub subject_var: Option<String>,
+}
+
+impl Trigger {
+    #[must_use]
+    pub fn matches(&self, event: &PluginEvent) -> bool {
+        self.event == event.event_type
+            && match &self.filter {
+                Some(filter) => event.subject.as_ref() == Some(filter),
+                None => true,
+            }
+    }
+}
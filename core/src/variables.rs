@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use tokio::sync::Mutex;
+
+fn current_timestamp() -> String {
+    // Getting the local offset can fail on some platforms, in which case UTC is used
+    let now = time::OffsetDateTime::now_local().unwrap_or_else(|_| time::OffsetDateTime::now_utc());
+
+    now.format(time::macros::format_description!(
+        "[year]-[month]-[day]_[hour]-[minute]-[second]"
+    ))
+    .unwrap_or_default()
+}
+
+// Runtime values shared between actions (not persisted between restarts)
+// Templated strings can reference these using {{name}}, unknown names are replaced with nothing
+// {{timestamp}} is the current local time (YYYY-MM-DD_HH-MM-SS) unless a variable overrides it
+#[derive(Default)]
+pub struct Variables(Mutex<HashMap<String, String>>);
+
+impl Variables {
+    pub async fn get(&self, name: &str) -> Option<String> {
+        self.0.lock().await.get(name).cloned()
+    }
+
+    pub async fn set(&self, name: &str, value: String) {
+        self.0.lock().await.insert(name.to_string(), value);
+    }
+
+    pub async fn render(&self, template: &str) -> String {
+        let vars = self.0.lock().await;
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(start) = rest.find("{{") {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+
+            let Some(end) = after.find("}}") else {
+                // No closing braces, so treat the rest as plain text
+                out.push_str(&rest[start..]);
+                return out;
+            };
+
+            let name = after[..end].trim();
+            if let Some(value) = vars.get(name) {
+                out.push_str(value);
+            } else if name == "timestamp" {
+                out.push_str(&current_timestamp());
+            }
+            rest = &after[end + 2..];
+        }
+
+        out.push_str(rest);
+        out
+    }
+
+    // Renders every string inside a JSON value, leaving keys and other types alone
+    pub async fn render_json(&self, value: &serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::String(s) => serde_json::Value::String(self.render(s).await),
+            serde_json::Value::Array(arr) => {
+                let mut out = Vec::with_capacity(arr.len());
+                for v in arr {
+                    out.push(Box::pin(self.render_json(v)).await);
+                }
+                serde_json::Value::Array(out)
+            }
+            serde_json::Value::Object(obj) => {
+                let mut out = serde_json::Map::with_capacity(obj.len());
+                for (k, v) in obj {
+                    out.insert(k.clone(), Box::pin(self.render_json(v)).await);
+                }
+                serde_json::Value::Object(out)
+            }
+            _ => value.clone(),
+        }
+    }
+}
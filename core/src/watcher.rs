@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use std::fs::read_to_string;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+use crate::action::{ActionConfig, Condition};
+use crate::plugins::{PluginQuery, PluginStates};
+
+// See examples/watchergen.rs on how to generate the watchers.json file
+#[derive(Serialize, Deserialize)]
+pub struct WatcherConfig {
+    pub watchers: Vec<Watcher>,
+}
+
+// Runs action_id every time the value is different from the previous poll
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WatchChange {
+    pub query: PluginQuery,
+    pub action_id: String,
+}
+
+// Runs on_true when the condition becomes true and on_false when it becomes false
+// The result has to be the same for confirm_count polls in a row before it counts, so a value that
+// flickers briefly doesn't run anything
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WatchCondition {
+    pub condition: Condition,
+    pub on_true: Option<String>,
+    pub on_false: Option<String>,
+    #[serde(default = "default_confirm_count")]
+    pub confirm_count: u32,
+}
+
+// For numeric values, becomes true once the value is at least rise and false once it is at most
+// fall, in between it stays as it was (so fall should be lower than rise)
+// on_true, on_false and confirm_count work the same as for WatchCondition
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WatchThreshold {
+    pub query: PluginQuery,
+    pub rise: f64,
+    pub fall: f64,
+    pub on_true: Option<String>,
+    pub on_false: Option<String>,
+    #[serde(default = "default_confirm_count")]
+    pub confirm_count: u32,
+}
+
+fn default_confirm_count() -> u32 {
+    1
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub enum WatchRule {
+    Change(WatchChange),
+    Condition(WatchCondition),
+    Threshold(WatchThreshold),
+}
+
+// The query is checked every interval_sec, and nothing runs for the first value (the state when the
+// server starts isn't a change)
+// If value_var is set the latest value is stored in that variable before any action runs
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Watcher {
+    pub name: String,
+    pub interval_sec: f64,
+    pub rule: WatchRule,
+    #[serde(default)]
+    pub value_var: Option<String>,
+}
+
+impl WatcherConfig {
+    #[must_use]
+    pub fn from_file(path: &str) -> WatcherConfig {
+        serde_json::from_str(&read_to_string(path).unwrap_or_else(|e| {
+            println!("{e}");
+            String::new()
+        }))
+        .unwrap_or_else(|e| {
+            println!("Unable to parse watcher config: {e}");
+            println!("Using defaults");
+            WatcherConfig {
+                watchers: Vec::new(),
+            }
+        })
+    }
+
+    // Polls every watcher for as long as the server runs
+    pub async fn run(self, actions: Arc<ActionConfig>, states: Arc<PluginStates>) {
+        futures_util::future::join_all(
+            self.watchers
+                .into_iter()
+                .map(|w| w.run(actions.clone(), states.clone())),
+        )
+        .await;
+    }
+}
+
+// Keeps track of the confirmed state of Condition and Threshold watchers
+#[derive(Default)]
+struct EdgeState {
+    confirmed: Option<bool>,
+    pending: Option<bool>,
+    count: u32,
+}
+
+impl EdgeState {
+    // Returns the new state if the confirmed state changed, the first confirmed state isn't a change
+    fn update(&mut self, reading: bool, confirm_count: u32) -> Option<bool> {
+        if self.confirmed == Some(reading) {
+            self.pending = None;
+            return None;
+        }
+
+        if self.pending == Some(reading) {
+            self.count += 1;
+        } else {
+            self.pending = Some(reading);
+            self.count = 1;
+        }
+
+        if self.count < confirm_count {
+            return None;
+        }
+
+        self.pending = None;
+        self.confirmed.replace(reading).map(|_| reading)
+    }
+}
+
+impl Watcher {
+    async fn run(self, actions: Arc<ActionConfig>, states: Arc<PluginStates>) {
+        let period = match Duration::try_from_secs_f64(self.interval_sec) {
+            Ok(period) if !period.is_zero() => period,
+            _ => {
+                println!(
+                    "Invalid interval for watcher {}: {}",
+                    self.name, self.interval_sec
+                );
+                return;
+            }
+        };
+
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        let mut last_value: Option<String> = None;
+        let mut edge = EdgeState::default();
+        // Only printed when it changes, so a plugin that's down doesn't fill the log
+        let mut last_error: Option<String> = None;
+
+        loop {
+            interval.tick().await;
+
+            let query = match &self.rule {
+                WatchRule::Change(data) => &data.query,
+                WatchRule::Condition(data) => &data.condition.query,
+                WatchRule::Threshold(data) => &data.query,
+            };
+
+            let value = match query.run(&states).await.and_then(|value| {
+                // Checked here so a non-numeric value is handled like any other error
+                if matches!(self.rule, WatchRule::Threshold(_))
+                    && value.trim().parse::<f64>().is_err()
+                {
+                    return Err(format!("{value} is not a number"));
+                }
+                Ok(value)
+            }) {
+                Ok(value) => {
+                    last_error = None;
+                    value
+                }
+                Err(e) => {
+                    if last_error.as_ref() != Some(&e) {
+                        println!("Watcher {} failed: {e}", self.name);
+                        last_error = Some(e);
+                    }
+                    continue;
+                }
+            };
+
+            if let Some(var) = &self.value_var {
+                states.variables.set(var, value.clone()).await;
+            }
+
+            let action_id = match &self.rule {
+                WatchRule::Change(data) => {
+                    let changed = last_value.as_ref().is_some_and(|last| *last != value);
+                    last_value = Some(value);
+                    changed.then_some(&data.action_id)
+                }
+                WatchRule::Condition(data) => edge
+                    .update(value == data.condition.target, data.confirm_count)
+                    .and_then(|state| {
+                        if state {
+                            data.on_true.as_ref()
+                        } else {
+                            data.on_false.as_ref()
+                        }
+                    }),
+                WatchRule::Threshold(data) => {
+                    let number = value.trim().parse::<f64>().unwrap_or_default();
+                    let reading = if number >= data.rise {
+                        Some(true)
+                    } else if number <= data.fall {
+                        Some(false)
+                    } else {
+                        // Inside the band, so whatever the state was stays
+                        edge.confirmed
+                    };
+
+                    reading
+                        .and_then(|reading| edge.update(reading, data.confirm_count))
+                        .and_then(|state| {
+                            if state {
+                                data.on_true.as_ref()
+                            } else {
+                                data.on_false.as_ref()
+                            }
+                        })
+                }
+            };
+
+            if let Some(action_id) = action_id {
+                actions.spawn(action_id, &states, "Watcher");
+            }
+        }
+    }
+}
@@ -1,10 +1,14 @@
 use actix_web::{get, web, App, HttpResponse, HttpServer, Responder};
 use directories::ProjectDirs;
 use handlebars::Handlebars;
+use std::collections::HashMap;
 
 use scuffcommander_core::action::ActionConfig;
-use scuffcommander_core::plugins::PluginStates;
+use scuffcommander_core::plugins::{PluginInstance, PluginStates, PluginType};
+use scuffcommander_core::schedule::ScheduleConfig;
+use scuffcommander_core::trigger::TriggerConfig;
 use scuffcommander_core::ui::UIConfig;
+use scuffcommander_core::watcher::WatcherConfig;
 use scuffcommander_core::AppConfig;
 
 #[get("/")]
@@ -45,13 +49,85 @@ async fn click(
         return format!("Action with ID {button} not configured");
     };
 
-    if let Err(e) = action.run(&data.plugins).await {
+    if let Err(e) = action.run(&data).await {
         return e;
     }
 
     "Success".to_string()
 }
 
+#[get("/status")]
+async fn status(hb: web::Data<Handlebars<'_>>, ui_data: web::Data<UIConfig>) -> impl Responder {
+    let data = serde_json::json!( { "style": ui_data.style } );
+    let body = hb.render("status", &data).expect("Template render failed");
+
+    HttpResponse::Ok().body(body)
+}
+
+#[get("/status/obs")]
+async fn obs_status(data: web::Data<PluginStates>) -> impl Responder {
+    let Some(PluginInstance::OBS(obs)) = data.plugins.get(&PluginType::OBS) else {
+        return HttpResponse::NotFound().body("OBS plugin not configured");
+    };
+
+    match obs.lock().await.get_stats().await {
+        Ok(stats) => HttpResponse::Ok().json(stats),
+        Err(e) => HttpResponse::InternalServerError().body(e),
+    }
+}
+
+#[get("/status/timers")]
+async fn timer_status(data: web::Data<PluginStates>) -> impl Responder {
+    let Some(PluginInstance::General(general)) = data.plugins.get(&PluginType::General) else {
+        return HttpResponse::NotFound().body("General plugin not configured");
+    };
+
+    HttpResponse::Ok().json(general.timers.get_status_list())
+}
+
+// The optional width query parameter scales the image down (keeping the aspect ratio)
+async fn screenshot(
+    source: Option<&str>,
+    query: &HashMap<String, String>,
+    data: &PluginStates,
+) -> HttpResponse {
+    let Some(PluginInstance::OBS(obs)) = data.plugins.get(&PluginType::OBS) else {
+        return HttpResponse::NotFound().body("OBS plugin not configured");
+    };
+
+    let width = match query.get("width").map(|w| w.parse::<u32>()).transpose() {
+        Ok(w) => w,
+        Err(e) => return HttpResponse::BadRequest().body(e.to_string()),
+    };
+
+    match obs
+        .lock()
+        .await
+        .get_screenshot(source, "jpg", width, None)
+        .await
+    {
+        Ok((mime, image)) => HttpResponse::Ok().content_type(mime).body(image),
+        Err(e) => HttpResponse::InternalServerError().body(e),
+    }
+}
+
+#[get("/screenshot")]
+async fn program_screenshot(
+    query: web::Query<HashMap<String, String>>,
+    data: web::Data<PluginStates>,
+) -> impl Responder {
+    screenshot(None, &query, &data).await
+}
+
+#[get("/screenshot/{source}")]
+async fn source_screenshot(
+    source: web::Path<String>,
+    query: web::Query<HashMap<String, String>>,
+    data: web::Data<PluginStates>,
+) -> impl Responder {
+    screenshot(Some(&source), &query, &data).await
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     let args: Vec<String> = std::env::args().collect();
@@ -78,7 +154,7 @@ async fn main() -> std::io::Result<()> {
     println!("Using {config_dir} as the config folder");
 
     let conf = AppConfig::from_file(&format!("{config_dir}/config.json"));
-    let state = web::Data::new(PluginStates::init(conf.plugins).await);
+    let state = web::Data::new(PluginStates::init(&conf, &config_dir).await);
 
     println!(
         "Starting the server at address http://{}:{}",
@@ -92,6 +168,12 @@ async fn main() -> std::io::Result<()> {
     handlebars
         .register_template_string("page", String::from_utf8_lossy(include_bytes!("page.html")))
         .expect("Unable to initialise Handlebars");
+    handlebars
+        .register_template_string(
+            "status",
+            String::from_utf8_lossy(include_bytes!("status.html")),
+        )
+        .expect("Unable to initialise Handlebars");
     let handlebars_ref = web::Data::new(handlebars);
 
     let actions_conf = web::Data::new(ActionConfig::from_file(&format!(
@@ -100,11 +182,43 @@ async fn main() -> std::io::Result<()> {
 
     let ui_conf = web::Data::new(UIConfig::from_file(&format!("{config_dir}/ui.json")));
 
+    let trigger_conf = TriggerConfig::from_file(&format!("{config_dir}/triggers.json"));
+    if !trigger_conf.triggers.is_empty() {
+        state
+            .start_event_listeners(&trigger_conf.get_event_types())
+            .await;
+        actix_web::rt::spawn(trigger_conf.run(
+            actions_conf.clone().into_inner(),
+            state.clone().into_inner(),
+        ));
+    }
+
+    let schedule_conf = ScheduleConfig::from_file(&format!("{config_dir}/schedules.json"));
+    if !schedule_conf.schedules.is_empty() {
+        actix_web::rt::spawn(schedule_conf.run(
+            actions_conf.clone().into_inner(),
+            state.clone().into_inner(),
+        ));
+    }
+
+    let watcher_conf = WatcherConfig::from_file(&format!("{config_dir}/watchers.json"));
+    if !watcher_conf.watchers.is_empty() {
+        actix_web::rt::spawn(watcher_conf.run(
+            actions_conf.clone().into_inner(),
+            state.clone().into_inner(),
+        ));
+    }
+
     HttpServer::new(move || {
         App::new()
             .service(hello)
             .service(click)
             .service(page)
+            .service(status)
+            .service(obs_status)
+            .service(timer_status)
+            .service(program_screenshot)
+            .service(source_screenshot)
             .app_data(state.clone())
             .app_data(handlebars_ref.clone())
             .app_data(actions_conf.clone())